@@ -0,0 +1,22 @@
+use aoc_common::solution::Day;
+
+/// Every day that can be run by the `aoc` binary.
+pub static DAYS: &[Day] = &[
+    day_one::DAY,
+    day_two::DAY,
+    day_three::DAY,
+    day_four::DAY,
+    day_five::DAY,
+    day_six::DAY,
+    day_seven::DAY,
+    day_eight::DAY,
+    day_nine::DAY,
+    day_ten::DAY,
+    day_eleven::DAY,
+    day_twelve::DAY,
+    day_thirteen::DAY,
+    day_fourteen::DAY,
+    day_fifteen::DAY,
+    day_sixteen::DAY,
+    day_seventeen::DAY,
+];
@@ -0,0 +1,146 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, Instant},
+};
+
+use thiserror::Error;
+
+/// The environment variable holding the `session` cookie of a logged in adventofcode.com user.
+pub const SESSION_VARIABLE: &str = "AOC_SESSION";
+
+const BASE_URL: &str = "https://adventofcode.com";
+
+const YEAR: u16 = 2024;
+
+/// The least time between two requests, to go easy on the servers of Advent of Code.
+const REQUEST_INTERVAL: Duration = Duration::from_secs(5);
+
+const USER_AGENT: &str = concat!(
+    "AdventOfCode2024 input fetcher ",
+    env!("CARGO_PKG_VERSION"),
+    " (ureq)"
+);
+
+#[derive(Debug, Error)]
+pub enum FetchError {
+    #[error(
+        "there is no session token, set AOC_SESSION to the session cookie of adventofcode.com"
+    )]
+    MissingSession,
+    #[error("the server answered {status}: {message}")]
+    Status { status: u16, message: String },
+    #[error("unable to download the input: {0}")]
+    Transport(Box<ureq::Transport>),
+    #[error("the server sent an empty input")]
+    EmptyInput,
+    #[error("unable to save the input: {0}")]
+    Io(#[from] io::Error),
+}
+
+impl From<ureq::Error> for FetchError {
+    fn from(error: ureq::Error) -> Self {
+        match error {
+            ureq::Error::Status(status, response) => Self::Status {
+                status,
+                message: response
+                    .into_string()
+                    .map(|body| body.trim().to_owned())
+                    .unwrap_or_default(),
+            },
+            ureq::Error::Transport(transport) => Self::Transport(Box::new(transport)),
+        }
+    }
+}
+
+/// Where a fetched input came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was already there, so nothing was downloaded
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Downloads personal puzzle inputs, at most one per interval. Inputs never change, so an input
+/// that has been saved before is never downloaded again.
+pub struct Fetcher {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    interval: Duration,
+    last_request: Option<Instant>,
+}
+
+impl Fetcher {
+    pub fn new(session: impl Into<String>) -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+            base_url: BASE_URL.to_owned(),
+            session: session.into(),
+            interval: REQUEST_INTERVAL,
+            last_request: None,
+        }
+    }
+
+    /// A fetcher with the session token from [`SESSION_VARIABLE`].
+    pub fn from_env() -> Result<Self, FetchError> {
+        match std::env::var(SESSION_VARIABLE) {
+            Ok(session) if !session.trim().is_empty() => Ok(Self::new(session.trim())),
+            _ => Err(FetchError::MissingSession),
+        }
+    }
+
+    /// Downloads from another server than adventofcode.com, such as a stub in tests.
+    pub fn with_base_url(self, base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+            ..self
+        }
+    }
+
+    pub fn with_interval(self, interval: Duration) -> Self {
+        Self { interval, ..self }
+    }
+
+    /// Saves the input of a day to `destination`, unless it already exists.
+    pub fn fetch(&mut self, day: u8, destination: &Path) -> Result<Fetched, FetchError> {
+        if destination.exists() {
+            return Ok(Fetched::Cached(destination.to_owned()));
+        }
+
+        self.wait_for_turn();
+        let response = self
+            .agent
+            .get(&format!("{}/{YEAR}/day/{day}/input", self.base_url))
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+        // The interval starts once the server is done, however long it took to answer
+        self.last_request = Some(Instant::now());
+        let input = response?.into_string()?;
+
+        if input.trim().is_empty() {
+            return Err(FetchError::EmptyInput);
+        }
+
+        // Write next to the destination first, so an interrupted save does not count as cached
+        let partial = destination.with_extension("part");
+        fs::write(&partial, input)?;
+        fs::rename(&partial, destination)?;
+
+        Ok(Fetched::Downloaded(destination.to_owned()))
+    }
+
+    fn wait_for_turn(&self) {
+        if let Some(last_request) = self.last_request {
+            let next_request = last_request + self.interval;
+            let now = Instant::now();
+            if now < next_request {
+                thread::sleep(next_request - now);
+            }
+        }
+    }
+}
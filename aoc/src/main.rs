@@ -0,0 +1,306 @@
+use std::process::ExitCode;
+
+use aoc::{
+    days::DAYS,
+    fetch::{Fetched, Fetcher},
+};
+use aoc_common::{
+    cli::{self, parse_options, Options, OPTIONS_USAGE},
+    input::InputSource,
+    timing,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+struct Args {
+    days: Vec<u8>,
+    options: Options,
+}
+
+const SELECTION_USAGE: &str = "<all | DAY | FIRST-LAST>...";
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1).peekable();
+    if args.peek().is_some_and(|arg| arg == "fetch") {
+        return fetch_inputs(args.skip(1));
+    }
+
+    let usage =
+        format!("Usage: aoc {SELECTION_USAGE} {OPTIONS_USAGE}\n       aoc fetch {SELECTION_USAGE}");
+    let args = match parse_args(args) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            println!("Runs the solutions of one or more days");
+            println!("{usage}");
+            return ExitCode::SUCCESS;
+        }
+        Err(message) => {
+            eprintln!("{message}");
+            eprintln!("{usage}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let Args { days, options } = args;
+    if let Err(message) = cli::configure_threads(&options) {
+        eprintln!("{message}");
+        return ExitCode::FAILURE;
+    }
+
+    let mut exit_code = ExitCode::SUCCESS;
+    let mut timings = vec![];
+
+    for day in DAYS.iter().filter(|day| days.contains(&day.number)) {
+        if let Some(directory) = &options.batch {
+            match cli::run_batch(day, directory, &options) {
+                Ok(true) => {}
+                Ok(false) => exit_code = ExitCode::FAILURE,
+                Err(message) => {
+                    eprintln!("Day {:>2}, {message}", day.number);
+                    exit_code = ExitCode::FAILURE;
+                }
+            }
+            continue;
+        }
+
+        // Only a single day can get its input from the command line or stdin
+        let source = match &options.input {
+            Some(source) => source.to_owned(),
+            None if days.len() == 1 => InputSource::Auto,
+            None => InputSource::File(day.input_files.default_input()),
+        };
+
+        let input = match cli::read_input(day, &options, &source) {
+            Ok(input) => input,
+            Err(message) => {
+                eprintln!("Day {:>2}, {message}", day.number);
+                exit_code = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        match cli::solve(day, &input, &options) {
+            Ok(Some(day_timings)) => timings.push((day.number, day_timings)),
+            Ok(None) => {}
+            Err(error) => {
+                eprintln!("Day {:>2}, {}", day.number, error.report(&input.text));
+                exit_code = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    if !timings.is_empty() {
+        println!();
+        print!(
+            "{}",
+            timing::table(timings.iter().map(|(day, timings)| (*day, timings)))
+        );
+    }
+
+    exit_code
+}
+
+/// Downloads the personal puzzle inputs of the selected days that are still missing.
+fn fetch_inputs(args: impl Iterator<Item = String>) -> ExitCode {
+    let prepared = select_days(args).and_then(|days| {
+        let fetcher = Fetcher::from_env().map_err(|error| error.to_string())?;
+        Ok((days, fetcher))
+    });
+    let (days, mut fetcher) = match prepared {
+        Ok(prepared) => prepared,
+        Err(message) => {
+            eprintln!("{message}");
+            eprintln!("Usage: aoc fetch {SELECTION_USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut exit_code = ExitCode::SUCCESS;
+    for day in DAYS.iter().filter(|day| days.contains(&day.number)) {
+        match fetcher.fetch(day.number, &day.input_files.puzzle()) {
+            Ok(Fetched::Cached(path)) => {
+                println!("Day {:>2}, already saved to {}", day.number, path.display())
+            }
+            Ok(Fetched::Downloaded(path)) => {
+                println!("Day {:>2}, saved to {}", day.number, path.display())
+            }
+            Err(error) => {
+                eprintln!("Day {:>2}, {error}", day.number);
+                exit_code = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    exit_code
+}
+
+/// Parses the days and options to run them with, or `None` when asked for help.
+fn parse_args(args: impl Iterator<Item = String>) -> Result<Option<Args>, String> {
+    let (options, selections) = parse_options(args)?;
+    if selections.iter().any(|arg| arg == "--help" || arg == "-h") {
+        return Ok(None);
+    }
+
+    let days = select_days(selections)?;
+
+    if options.input.is_some() && days.len() > 1 {
+        return Err("--input can only be used when running a single day".to_owned());
+    }
+
+    if options.batch.is_some() && days.len() > 1 {
+        return Err("--batch can only be used when running a single day".to_owned());
+    }
+
+    if options.visualize.is_some() && days.len() > 1 {
+        return Err("--visualize can only be used when running a single day".to_owned());
+    }
+
+    if options.image.is_some() && days.len() > 1 {
+        return Err("--image can only be used when running a single day".to_owned());
+    }
+
+    Ok(Some(Args { days, options }))
+}
+
+/// The sorted days of all selections, every one of them `all`, a day or a range of days.
+fn select_days(selections: impl IntoIterator<Item = String>) -> Result<Vec<u8>, String> {
+    let mut days = vec![];
+
+    for selection in selections {
+        match selection.as_str() {
+            "all" => days.extend(DAYS.iter().map(|day| day.number)),
+            selection => days.extend(parse_day_selection(selection)?),
+        }
+    }
+
+    if days.is_empty() {
+        return Err("No days selected".to_owned());
+    }
+
+    days.sort();
+    days.dedup();
+    Ok(days)
+}
+
+/// Parses a single day (`16`) or an inclusive range of days (`3-7`). Ranges silently skip
+/// days that have not been solved, a single unknown day is an error.
+fn parse_day_selection(selection: &str) -> Result<Vec<u8>, String> {
+    let parse_day = |day: &str| {
+        day.trim()
+            .parse::<u8>()
+            .map_err(|_| format!("'{selection}' is not a day or a range of days"))
+    };
+
+    let selected_days: Vec<u8> = match selection.split_once('-') {
+        Some((first, last)) => (parse_day(first)?..=parse_day(last)?)
+            .filter(|number| DAYS.iter().any(|day| day.number == *number))
+            .collect(),
+        None => {
+            let number = parse_day(selection)?;
+            if !DAYS.iter().any(|day| day.number == number) {
+                return Err(format!("Day {number} has not been solved (yet)"));
+            }
+            vec![number]
+        }
+    };
+
+    if selected_days.is_empty() {
+        Err(format!("There are no solved days in '{selection}'"))
+    } else {
+        Ok(selected_days)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{cli::Options, input::InputSource, solution::Part};
+
+    use crate::{parse_args, parse_day_selection, Args};
+
+    fn parse(args: &[&str]) -> Result<Option<Args>, String> {
+        parse_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    fn args(args: &[&str]) -> Result<Args, String> {
+        parse(args)?.ok_or_else(|| "Asked for help instead".to_owned())
+    }
+
+    #[test]
+    fn help_is_not_an_error() {
+        assert_eq!(parse(&["--help"]), Ok(None));
+        assert_eq!(parse(&["3", "-h"]), Ok(None));
+    }
+
+    #[test]
+    fn single_day_with_part() {
+        let expected = Args {
+            days: vec![16],
+            options: Options {
+                part: Some(Part::Two),
+                ..Options::default()
+            },
+        };
+
+        assert_eq!(args(&["16", "--part", "2"]), Ok(expected));
+    }
+
+    #[test]
+    fn range_of_days() {
+        assert_eq!(parse_day_selection("3-7"), Ok(vec![3, 4, 5, 6, 7]));
+    }
+
+    #[test]
+    fn range_skips_unsolved_days() {
+        assert_eq!(parse_day_selection("16-25"), Ok(vec![16, 17]));
+    }
+
+    #[test]
+    fn unsolved_day_is_rejected() {
+        assert!(parse_day_selection("25").is_err());
+    }
+
+    #[test]
+    fn all_days_are_sorted_and_deduplicated() {
+        let parsed = args(&["5", "all"]).unwrap();
+
+        assert_eq!(parsed.days, (1..=17).collect::<Vec<_>>());
+        assert_eq!(parsed.options.part, None);
+    }
+
+    #[test]
+    fn input_for_single_day() {
+        let parsed = args(&["9", "--input", "-"]).unwrap();
+
+        assert_eq!(parsed.options.input, Some(InputSource::Stdin));
+    }
+
+    #[test]
+    fn input_for_multiple_days_is_rejected() {
+        assert!(args(&["1-3", "--input", "input.txt"]).is_err());
+    }
+
+    #[test]
+    fn visualize_for_multiple_days_is_rejected() {
+        assert!(args(&["6", "--visualize"]).is_ok());
+        assert!(args(&["6", "14", "--visualize"]).is_err());
+    }
+
+    #[test]
+    fn bench_all_days() {
+        let parsed = args(&["all", "--bench", "5"]).unwrap();
+
+        assert_eq!(parsed.options.runs, Some(5));
+    }
+
+    #[test]
+    fn json_report_of_a_range() {
+        let parsed = args(&["1-3", "--json"]).unwrap();
+
+        assert_eq!(parsed.days, vec![1, 2, 3]);
+        assert!(parsed.options.json);
+    }
+
+    #[test]
+    fn unknown_part_is_rejected() {
+        assert!(args(&["1", "--part", "3"]).is_err());
+    }
+}
@@ -0,0 +1,97 @@
+use std::fs;
+
+use aoc::days::DAYS;
+use aoc_common::answers;
+
+/// Solves a day for every input in its answers file. Inputs that are missing are skipped, as
+/// personal puzzle inputs are not part of the repository, but the example always has to exist.
+fn check_recorded_answers(number: u8) {
+    let day = DAYS
+        .iter()
+        .find(|day| day.number == number)
+        .expect("Day should be solved");
+
+    let recorded_answers = answers::read(&day.input_files.answers()).unwrap();
+    let example = day.input_files.example();
+
+    assert!(
+        recorded_answers
+            .iter()
+            .any(|expected| day.input_files.directory().join(&expected.input) == example),
+        "Day {number} should have recorded answers for its example"
+    );
+
+    for expected in recorded_answers {
+        let input_file = day.input_files.directory().join(&expected.input);
+        if !input_file.exists() {
+            eprintln!(
+                "Day {number}: skipping {}, it does not exist",
+                expected.input
+            );
+            continue;
+        }
+
+        let input = fs::read_to_string(input_file).unwrap();
+        let answers = (day.run)(&input, expected.part())
+            .unwrap_or_else(|error| panic!("Day {number}, {}: {error}", expected.input))
+            .answers;
+
+        if expected.part_one.is_some() {
+            assert_eq!(
+                answers.part_one, expected.part_one,
+                "Day {number}, part 1 of {}",
+                expected.input
+            );
+        }
+
+        if expected.part_two.is_some() {
+            assert_eq!(
+                answers.part_two, expected.part_two,
+                "Day {number}, part 2 of {}",
+                expected.input
+            );
+        }
+    }
+}
+
+macro_rules! recorded_answers_tests {
+    ($($name:ident: $day:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                check_recorded_answers($day);
+            }
+        )*
+    };
+}
+
+recorded_answers_tests! {
+    day_one: 1,
+    day_two: 2,
+    day_three: 3,
+    day_four: 4,
+    day_five: 5,
+    day_six: 6,
+    day_seven: 7,
+    day_eight: 8,
+    day_nine: 9,
+    day_ten: 10,
+    day_eleven: 11,
+    day_twelve: 12,
+    day_thirteen: 13,
+    day_fourteen: 14,
+    day_fifteen: 15,
+    day_sixteen: 16,
+    day_seventeen: 17,
+}
+
+#[test]
+fn every_day_has_recorded_answers() {
+    for day in DAYS {
+        assert!(
+            day.input_files.answers().exists(),
+            "Day {} has no answers file",
+            day.number
+        );
+    }
+}
@@ -0,0 +1,154 @@
+use std::{
+    fs,
+    io::{BufRead, BufReader, Write},
+    net::TcpListener,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc::fetch::{FetchError, Fetched, Fetcher};
+
+const SESSION: &str = "0123abcd";
+
+/// A request the stub server has answered.
+#[derive(Debug, Clone)]
+struct Request {
+    at: Instant,
+    path: String,
+    cookie: Option<String>,
+}
+
+/// Serves puzzle inputs like adventofcode.com on a local port, recording every request. Only
+/// requests with the session cookie of [`SESSION`] get an input.
+fn stub_server() -> (String, Arc<Mutex<Vec<Request>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(Mutex::new(vec![]));
+
+    let recorded = Arc::clone(&requests);
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut lines = BufReader::new(&stream).lines().map_while(Result::ok);
+
+            let request_line = lines.next().unwrap_or_default();
+            let path = request_line
+                .split(' ')
+                .nth(1)
+                .unwrap_or_default()
+                .to_owned();
+            let cookie = lines
+                .take_while(|line| !line.is_empty())
+                .find_map(|line| line.strip_prefix("Cookie: ").map(str::to_owned));
+
+            let (status, body) = if cookie.as_deref() == Some(&format!("session={SESSION}")) {
+                ("200 OK", format!("input of {path}\n"))
+            } else {
+                (
+                    "400 Bad Request",
+                    "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"
+                        .to_owned(),
+                )
+            };
+
+            recorded.lock().unwrap().push(Request {
+                at: Instant::now(),
+                path,
+                cookie,
+            });
+
+            let response = format!(
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            stream.write_all(response.as_bytes()).unwrap();
+        }
+    });
+
+    (base_url, requests)
+}
+
+/// An empty directory for the inputs of a single test.
+fn directory(test: &str) -> PathBuf {
+    let directory = std::env::temp_dir().join(format!("aoc_fetch_{}_{test}", std::process::id()));
+    let _ = fs::remove_dir_all(&directory);
+    fs::create_dir_all(&directory).unwrap();
+    directory
+}
+
+#[test]
+fn downloads_an_input_once() {
+    let (base_url, requests) = stub_server();
+    let destination = directory("once").join("input.txt");
+    let mut fetcher = Fetcher::new(SESSION).with_base_url(base_url);
+
+    assert_eq!(
+        fetcher.fetch(7, &destination).unwrap(),
+        Fetched::Downloaded(destination.clone())
+    );
+    assert_eq!(
+        fetcher.fetch(7, &destination).unwrap(),
+        Fetched::Cached(destination.clone())
+    );
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].path, "/2024/day/7/input");
+    assert_eq!(requests[0].cookie.as_deref(), Some("session=0123abcd"));
+    assert_eq!(
+        fs::read_to_string(&destination).unwrap(),
+        "input of /2024/day/7/input\n"
+    );
+}
+
+#[test]
+fn existing_inputs_are_never_downloaded() {
+    let (base_url, requests) = stub_server();
+    let destination = directory("existing").join("input.txt");
+    fs::write(&destination, "saved by hand\n").unwrap();
+
+    let fetched = Fetcher::new(SESSION)
+        .with_base_url(base_url)
+        .fetch(1, &destination);
+
+    assert_eq!(fetched.unwrap(), Fetched::Cached(destination.clone()));
+    assert!(requests.lock().unwrap().is_empty());
+    assert_eq!(fs::read_to_string(&destination).unwrap(), "saved by hand\n");
+}
+
+#[test]
+fn requests_are_rate_limited() {
+    let (base_url, requests) = stub_server();
+    let directory = directory("rate_limited");
+    let mut fetcher = Fetcher::new(SESSION)
+        .with_base_url(base_url)
+        .with_interval(Duration::from_millis(300));
+
+    for day in 1..=3 {
+        let destination = directory.join(format!("input_{day}.txt"));
+        fetcher.fetch(day, &destination).unwrap();
+    }
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 3);
+    for pair in requests.windows(2) {
+        assert!(pair[1].at - pair[0].at >= Duration::from_millis(300));
+    }
+}
+
+#[test]
+fn rejected_session_saves_nothing() {
+    let (base_url, _) = stub_server();
+    let destination = directory("rejected").join("input.txt");
+
+    let error = Fetcher::new("expired")
+        .with_base_url(base_url)
+        .fetch(3, &destination)
+        .unwrap_err();
+
+    assert!(matches!(error, FetchError::Status { status: 400, .. }));
+    assert!(error.to_string().contains("Please log in"));
+    assert!(!destination.exists());
+}
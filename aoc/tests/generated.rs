@@ -0,0 +1,22 @@
+use aoc::days::DAYS;
+
+/// Every day can generate inputs, and those inputs are valid for any seed and size.
+#[test]
+fn every_day_generates_valid_inputs() {
+    for day in DAYS {
+        let generate = day
+            .generate
+            .unwrap_or_else(|| panic!("Day {} should have a generator", day.number));
+
+        for (seed, size) in [(0, 1), (1, 5), (2, 20)] {
+            let input = generate(seed, size);
+            if let Err(error) = (day.check)(&input) {
+                panic!(
+                    "Day {}, seed {seed}, size {size}: {}",
+                    day.number,
+                    error.report(&input)
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use std::{fs, io, path::Path};
+
+use crate::solution::{Answers, Part};
+
+/// The answers a day should give for one of its input files, as recorded in an answers file.
+///
+/// Every line of an answers file contains the name of an input file followed by the answers of
+/// both parts, separated by whitespace. An answer of `-` has not been recorded (for example
+/// because it takes too long to compute). Empty lines and lines starting with `#` are ignored:
+///
+/// ```text
+/// # input          part one  part two
+/// input_small.txt  143       123
+/// input.txt        5208      -
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpectedAnswers {
+    pub input: String,
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+impl ExpectedAnswers {
+    /// The part to solve in order to check these answers, `None` meaning both parts.
+    pub fn part(&self) -> Option<Part> {
+        match (&self.part_one, &self.part_two) {
+            (Some(_), None) => Some(Part::One),
+            (None, Some(_)) => Some(Part::Two),
+            _ => None,
+        }
+    }
+
+    /// Whether the given answers match, ignoring parts that have not been recorded or have not
+    /// been solved (such as when only one part was asked for).
+    pub fn matches(&self, answers: &Answers) -> bool {
+        let part_matches = |expected: &Option<String>, actual: &Option<String>| {
+            expected.is_none() || actual.is_none() || expected == actual
+        };
+
+        part_matches(&self.part_one, &answers.part_one)
+            && part_matches(&self.part_two, &answers.part_two)
+    }
+
+    /// Whether any part of the given answers has been recorded, so that matching them compares
+    /// at least one answer.
+    pub fn checks(&self, answers: &Answers) -> bool {
+        (self.part_one.is_some() && answers.part_one.is_some())
+            || (self.part_two.is_some() && answers.part_two.is_some())
+    }
+}
+
+pub fn read(path: &Path) -> io::Result<Vec<ExpectedAnswers>> {
+    parse(&fs::read_to_string(path)?).map_err(|message| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{}: {message}", path.display()),
+        )
+    })
+}
+
+pub fn parse(input: &str) -> Result<Vec<ExpectedAnswers>, String> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(line_number, line)| {
+            let recorded = |answer: &str| (answer != "-").then(|| answer.to_owned());
+
+            match line.split_whitespace().collect::<Vec<_>>()[..] {
+                [input, part_one, part_two] => Ok(ExpectedAnswers {
+                    input: input.to_owned(),
+                    part_one: recorded(part_one),
+                    part_two: recorded(part_two),
+                }),
+                _ => Err(format!(
+                    "line {line_number} should contain an input file and two answers"
+                )),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        answers::{parse, ExpectedAnswers},
+        solution::{Answers, Part},
+    };
+
+    #[test]
+    fn parses_answers_and_skips_comments() {
+        let parsed = parse("# input one two\n\ninput_small.txt 5,7,3,0 -\n").unwrap();
+
+        let expected = ExpectedAnswers {
+            input: "input_small.txt".to_owned(),
+            part_one: Some("5,7,3,0".to_owned()),
+            part_two: None,
+        };
+
+        assert_eq!(parsed, vec![expected]);
+        assert_eq!(parsed[0].part(), Some(Part::One));
+    }
+
+    #[test]
+    fn missing_answers_are_reported_with_their_line() {
+        assert_eq!(
+            parse("input.txt 1 2\ninput_small.txt 3"),
+            Err("line 2 should contain an input file and two answers".to_owned())
+        );
+    }
+
+    #[test]
+    fn unrecorded_parts_always_match() {
+        let expected = ExpectedAnswers {
+            input: "input.txt".to_owned(),
+            part_one: None,
+            part_two: Some("2".to_owned()),
+        };
+
+        let answers = |part_two: &str| Answers {
+            part_one: Some("1".to_owned()),
+            part_two: Some(part_two.to_owned()),
+        };
+
+        assert!(expected.matches(&answers("2")));
+        assert!(!expected.matches(&answers("3")));
+    }
+
+    #[test]
+    fn unsolved_parts_always_match() {
+        let expected = ExpectedAnswers {
+            input: "input.txt".to_owned(),
+            part_one: Some("1".to_owned()),
+            part_two: Some("2".to_owned()),
+        };
+        let part_one = Answers {
+            part_one: Some("1".to_owned()),
+            part_two: None,
+        };
+
+        assert!(expected.matches(&part_one));
+        assert!(expected.checks(&part_one));
+        assert!(!expected.checks(&Answers::default()));
+    }
+}
@@ -0,0 +1,300 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    answers::{self, ExpectedAnswers},
+    solution::{Answers, Day, Part},
+};
+
+/// Name of the file in a batch directory that records the expected answers of its inputs, in
+/// the format described at [`ExpectedAnswers`]. Every other file is an input.
+const ANSWERS_FILE: &str = "answers.txt";
+
+/// How solving a single input of a batch turned out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// Every recorded answer matches
+    Pass,
+    /// At least one answer differs from the recorded one
+    Mismatch,
+    /// Solved, but there are no answers recorded to compare with
+    Unchecked,
+    /// The input could not be read or solved
+    Fail(String),
+}
+
+impl Outcome {
+    /// Whether nothing went wrong, unchecked answers might still be wrong though.
+    pub fn is_success(&self) -> bool {
+        matches!(self, Self::Pass | Self::Unchecked)
+    }
+}
+
+/// The answers for a single input of a batch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Checked {
+    /// The file name of the input
+    pub input: String,
+    pub answers: Answers,
+    pub expected: Option<ExpectedAnswers>,
+    pub outcome: Outcome,
+}
+
+/// Solves a day for every input in a directory, in the order of their names, comparing the
+/// answers with those recorded in its answers file (if there is one). Only the given part is
+/// solved, or both parts when no part is given, unless only one part has been recorded for an
+/// input. Failing to read the directory or its answers file is an error, failing to solve an
+/// input is an outcome.
+pub fn run(day: &Day, directory: &Path, part: Option<Part>) -> io::Result<Vec<Checked>> {
+    let answers_file = directory.join(ANSWERS_FILE);
+    let recorded_answers = if answers_file.exists() {
+        answers::read(&answers_file)?
+    } else {
+        vec![]
+    };
+
+    let mut inputs: Vec<PathBuf> = fs::read_dir(directory)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<io::Result<_>>()?;
+    inputs.retain(|path| path.is_file() && *path != answers_file);
+    inputs.sort();
+
+    Ok(inputs
+        .iter()
+        .map(|path| {
+            let input = path
+                .file_name()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .into_owned();
+            let expected = recorded_answers
+                .iter()
+                .find(|expected| expected.input == input)
+                .cloned();
+
+            check(day, path, input, expected, part)
+        })
+        .collect())
+}
+
+fn check(
+    day: &Day,
+    path: &Path,
+    input: String,
+    expected: Option<ExpectedAnswers>,
+    part: Option<Part>,
+) -> Checked {
+    let part = part.or(expected.as_ref().and_then(ExpectedAnswers::part));
+    let solved = fs::read_to_string(path)
+        .map_err(|error| format!("unable to read input: {error}"))
+        .and_then(|text| (day.run)(&text, part).map_err(|error| error.to_string()));
+
+    let (answers, outcome) = match solved {
+        Ok(run) => {
+            let outcome = match &expected {
+                None => Outcome::Unchecked,
+                Some(expected) if !expected.checks(&run.answers) => Outcome::Unchecked,
+                Some(expected) if expected.matches(&run.answers) => Outcome::Pass,
+                Some(_) => Outcome::Mismatch,
+            };
+            (run.answers, outcome)
+        }
+        Err(message) => (Answers::default(), Outcome::Fail(message)),
+    };
+
+    Checked {
+        input,
+        answers,
+        expected,
+        outcome,
+    }
+}
+
+/// Formats the results of a batch as a table with a row per input, followed by a summary line.
+/// Answers that differ from the recorded ones show the expected answer next to them.
+pub fn table(results: &[Checked]) -> String {
+    let answer = |actual: &Option<String>, expected: Option<&Option<String>>| match (
+        actual,
+        expected.and_then(Option::as_ref),
+    ) {
+        (Some(actual), Some(expected)) if actual != expected => {
+            format!("{actual} (expected {expected})")
+        }
+        (Some(actual), _) => actual.to_owned(),
+        (None, _) => "-".to_owned(),
+    };
+
+    let rows: Vec<[String; 4]> = results
+        .iter()
+        .map(|result| {
+            let expected = result.expected.as_ref();
+            [
+                result.input.clone(),
+                answer(
+                    &result.answers.part_one,
+                    expected.map(|expected| &expected.part_one),
+                ),
+                answer(
+                    &result.answers.part_two,
+                    expected.map(|expected| &expected.part_two),
+                ),
+                match &result.outcome {
+                    Outcome::Pass => "pass".to_owned(),
+                    Outcome::Mismatch => "mismatch".to_owned(),
+                    Outcome::Unchecked => "unchecked".to_owned(),
+                    Outcome::Fail(message) => format!("fail, {message}"),
+                },
+            ]
+        })
+        .collect();
+
+    let header = ["Input", "Part 1", "Part 2", "Result"].map(str::to_owned);
+    let widths: Vec<usize> = (0..3)
+        .map(|column| {
+            rows.iter()
+                .chain([&header])
+                .map(|row| row[column].chars().count())
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+
+    let mut table = String::new();
+    for row in [&header].into_iter().chain(&rows) {
+        table.push_str(&format!(
+            "{:<input$}  {:>part_one$}  {:>part_two$}  {}\n",
+            row[0],
+            row[1],
+            row[2],
+            row[3],
+            input = widths[0],
+            part_one = widths[1],
+            part_two = widths[2],
+        ));
+    }
+
+    let count = |outcome: fn(&Outcome) -> bool| {
+        results
+            .iter()
+            .filter(|result| outcome(&result.outcome))
+            .count()
+    };
+    table.push_str(&format!(
+        "{} inputs: {} passed, {} mismatched, {} failed, {} unchecked\n",
+        results.len(),
+        count(|outcome| *outcome == Outcome::Pass),
+        count(|outcome| *outcome == Outcome::Mismatch),
+        count(|outcome| matches!(outcome, Outcome::Fail(_))),
+        count(|outcome| *outcome == Outcome::Unchecked),
+    ));
+
+    table
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fs, num::ParseIntError};
+
+    use crate::{
+        batch::{run, table, Outcome},
+        input::InputFiles,
+        solution::{Day, Part, Solution},
+    };
+
+    const FILES: InputFiles = InputFiles::new(env!("CARGO_MANIFEST_DIR"), "example.txt");
+
+    /// Sums and counts the numbers on every line.
+    struct Numbers;
+
+    impl Solution for Numbers {
+        type Input = Vec<u32>;
+        type PartOne = u32;
+        type PartTwo = usize;
+        type Error = ParseIntError;
+
+        fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+            input.lines().map(str::parse).collect()
+        }
+
+        fn part_one(input: &Self::Input) -> Result<Self::PartOne, Self::Error> {
+            Ok(input.iter().sum())
+        }
+
+        fn part_two(input: &Self::Input) -> Result<Self::PartTwo, Self::Error> {
+            Ok(input.len())
+        }
+    }
+
+    const DAY: Day = Day::new::<Numbers>(1, &FILES);
+
+    #[test]
+    fn every_input_gets_an_outcome() {
+        let directory = std::env::temp_dir().join(format!("aoc_batch_{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        for (name, text) in [
+            ("alice.txt", "1\n2\n"),
+            ("bob.txt", "5\n"),
+            ("carol.txt", "3\nx\n"),
+            ("dave.txt", "4\n"),
+            (
+                "answers.txt",
+                "alice.txt 3 2\nbob.txt 5 2\ncarol.txt 3 -\ndave.txt - -\n",
+            ),
+        ] {
+            fs::write(directory.join(name), text).unwrap();
+        }
+
+        let results = run(&DAY, &directory, None).unwrap();
+        fs::remove_dir_all(&directory).unwrap();
+
+        let outcomes: Vec<_> = results
+            .iter()
+            .map(|result| (result.input.as_str(), &result.outcome))
+            .collect();
+        assert_eq!(
+            outcomes[..3],
+            [
+                ("alice.txt", &Outcome::Pass),
+                ("bob.txt", &Outcome::Mismatch),
+                (
+                    "carol.txt",
+                    &Outcome::Fail("invalid input, invalid digit found in string".to_owned())
+                ),
+            ]
+        );
+        assert_eq!(outcomes[3], ("dave.txt", &Outcome::Unchecked));
+
+        let table = table(&results);
+        assert!(table.contains("bob.txt         5  1 (expected 2)  mismatch\n"));
+        assert!(table.ends_with("4 inputs: 1 passed, 1 mismatched, 1 failed, 1 unchecked\n"));
+    }
+
+    #[test]
+    fn only_the_given_part_is_checked() {
+        let directory = std::env::temp_dir().join(format!("aoc_batch_part_{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        fs::write(directory.join("alice.txt"), "1\n2\n").unwrap();
+        fs::write(directory.join("bob.txt"), "5\n").unwrap();
+        fs::write(
+            directory.join("answers.txt"),
+            "alice.txt 3 2\nbob.txt - 1\n",
+        )
+        .unwrap();
+
+        let results = run(&DAY, &directory, Some(Part::One)).unwrap();
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(results[0].outcome, Outcome::Pass);
+        assert_eq!(results[0].answers.part_two, None);
+        // Only part two was recorded, which was not solved
+        assert_eq!(results[1].outcome, Outcome::Unchecked);
+        assert!(table(&results).contains("alice.txt       3       -  pass\n"));
+    }
+
+    #[test]
+    fn a_missing_directory_is_an_error() {
+        assert!(run(&DAY, &FILES.directory().join("missing"), None).is_err());
+    }
+}
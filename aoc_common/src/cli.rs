@@ -0,0 +1,427 @@
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use crate::{
+    batch,
+    image::ImageFormat,
+    input::{Input, InputSource},
+    report::Report,
+    solution::{Answers, Day, Part, SolveError},
+    timing::{self, BenchTimings},
+    visualize::{VisualizeOptions, Visualizer},
+};
+
+/// Usage of the options that every binary supports.
+pub const OPTIONS_USAGE: &str = concat!(
+    "[--part <1|2>] [--input <file | -> | --generate <size> [--seed <n>] | --batch <dir>] ",
+    "[--check | --time | --bench <runs>] [--json] [--threads <n> | --sequential] ",
+    "[--visualize [--fps <frames>] [--step] [--frames <dir>]] ",
+    "[--image <file.ppm | .pbm | .svg> [--at <moment>]]"
+);
+
+/// The size in pixels of a single cell of an exported image.
+const IMAGE_SCALE: usize = 8;
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Options {
+    pub part: Option<Part>,
+    pub input: Option<InputSource>,
+    /// Solve a generated input of this size instead of reading one
+    pub generate: Option<usize>,
+    /// The seed of the generated input
+    pub seed: u64,
+    /// Solve every input in this directory, comparing the answers with its answers file
+    pub batch: Option<PathBuf>,
+    /// How many times to run a day while measuring every phase, not measured when `None`
+    pub runs: Option<usize>,
+    /// Print a JSON report per day instead of text
+    pub json: bool,
+    /// How many threads the parallel parts of the days use, all cores when `None`
+    pub threads: Option<usize>,
+    /// Only parse the input, reporting whether it is valid
+    pub check: bool,
+    /// Show the simulation of a day instead of solving it
+    pub visualize: Option<VisualizeOptions>,
+    /// Draw the state of a day to this file instead of solving it
+    pub image: Option<PathBuf>,
+    /// The moment to draw, such as a second of a simulation
+    pub at: Option<usize>,
+}
+
+/// Parses the options shared by the day binaries and the `aoc` runner. Any other argument is
+/// returned (in order) for the caller to interpret.
+pub fn parse_options(
+    mut args: impl Iterator<Item = String>,
+) -> Result<(Options, Vec<String>), String> {
+    let mut options = Options::default();
+    let mut other_args = vec![];
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" | "-p" => {
+                options.part = match args.next().as_deref() {
+                    Some("1") => Some(Part::One),
+                    Some("2") => Some(Part::Two),
+                    Some(other) => return Err(format!("There is no part '{other}'")),
+                    None => return Err("--part needs a value".to_owned()),
+                }
+            }
+            "--input" | "-i" => match args.next() {
+                Some(value) => options.input = Some(InputSource::from_arg(&value)),
+                None => return Err("--input needs a file, or '-' for stdin".to_owned()),
+            },
+            "--generate" | "-g" => match args.next().map(|size| size.parse::<usize>()) {
+                Some(Ok(size)) if size > 0 => options.generate = Some(size),
+                _ => return Err("--generate needs a positive size".to_owned()),
+            },
+            "--seed" => match args.next().map(|seed| seed.parse::<u64>()) {
+                Some(Ok(seed)) => options.seed = seed,
+                _ => return Err("--seed needs a number".to_owned()),
+            },
+            "--batch" => match args.next() {
+                Some(directory) => options.batch = Some(PathBuf::from(directory)),
+                None => return Err("--batch needs a directory of inputs".to_owned()),
+            },
+            "--time" | "-t" => options.runs = Some(1),
+            "--json" => options.json = true,
+            "--threads" => match args.next().map(|threads| threads.parse::<usize>()) {
+                Some(Ok(threads)) if threads > 0 => options.threads = Some(threads),
+                _ => return Err("--threads needs a positive amount of threads".to_owned()),
+            },
+            "--sequential" => options.threads = Some(1),
+            "--check" | "-c" => options.check = true,
+            "--bench" | "-b" => {
+                options.runs = match args.next().map(|runs| runs.parse::<usize>()) {
+                    Some(Ok(runs)) if runs > 0 => Some(runs),
+                    _ => return Err("--bench needs a positive amount of runs".to_owned()),
+                }
+            }
+            "--visualize" | "-v" => {
+                options
+                    .visualize
+                    .get_or_insert_with(VisualizeOptions::default);
+            }
+            "--fps" => match args.next().map(|fps| fps.parse::<u32>()) {
+                Some(Ok(fps)) if fps > 0 => {
+                    options
+                        .visualize
+                        .get_or_insert_with(VisualizeOptions::default)
+                        .frame_rate = fps
+                }
+                _ => return Err("--fps needs a positive amount of frames".to_owned()),
+            },
+            "--step" => {
+                options
+                    .visualize
+                    .get_or_insert_with(VisualizeOptions::default)
+                    .step = true
+            }
+            "--frames" => match args.next() {
+                Some(directory) => {
+                    options
+                        .visualize
+                        .get_or_insert_with(VisualizeOptions::default)
+                        .headless = Some(PathBuf::from(directory))
+                }
+                None => return Err("--frames needs a directory".to_owned()),
+            },
+            "--image" => match args.next().map(PathBuf::from) {
+                Some(path) if ImageFormat::from_path(&path).is_some() => options.image = Some(path),
+                _ => return Err("--image needs a file ending with .ppm, .pbm or .svg".to_owned()),
+            },
+            "--at" => match args.next().map(|moment| moment.parse::<usize>()) {
+                Some(Ok(moment)) => options.at = Some(moment),
+                _ => return Err("--at needs a moment, such as a second".to_owned()),
+            },
+            _ => other_args.push(arg),
+        }
+    }
+
+    if options.input.is_some() && options.generate.is_some() {
+        return Err("--input and --generate can't be used together".to_owned());
+    }
+
+    if options.batch.is_some() && (options.input.is_some() || options.generate.is_some()) {
+        return Err(
+            "--batch reads its own inputs, so it can't be used with --input or --generate"
+                .to_owned(),
+        );
+    }
+
+    Ok((options, other_args))
+}
+
+/// Sets up the thread pool that the parallel parts of all days share, for the rest of the run.
+/// Without a number of threads set in the options, the pool uses every core.
+pub fn configure_threads(options: &Options) -> Result<(), String> {
+    let Some(threads) = options.threads else {
+        return Ok(());
+    };
+
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build_global()
+        .map_err(|error| format!("Unable to start {threads} threads: {error}"))
+}
+
+/// Reads the input of a day from the source, or generates one when the options ask for it.
+pub fn read_input(day: &Day, options: &Options, source: &InputSource) -> Result<Input, String> {
+    match options.generate {
+        Some(size) => {
+            let generate = day.generate.ok_or(SolveError::NoGenerator.to_string())?;
+            Ok(Input {
+                name: format!("generated input of size {size} (seed {})", options.seed),
+                text: generate(options.seed, size),
+            })
+        }
+        None => source
+            .read(day.input_files)
+            .map_err(|error| format!("unable to read input: {error}")),
+    }
+}
+
+/// Solves a day for every input in a directory and prints how each of them turned out, see
+/// [`batch::run`]. Returns whether every input was solved without a mismatch.
+pub fn run_batch(day: &Day, directory: &Path, options: &Options) -> Result<bool, String> {
+    let results = batch::run(day, directory, options.part)
+        .map_err(|error| format!("unable to run batch {}: {error}", directory.display()))?;
+
+    println!("Day {:>2}, batch {}", day.number, directory.display());
+    print!("{}", batch::table(&results));
+    Ok(results.iter().all(|result| result.outcome.is_success()))
+}
+
+/// The `main` of a day binary: solves the day for the input selected on the command line.
+pub fn main(day: &Day) -> ExitCode {
+    let mut args = std::env::args();
+    let binary = args.next().unwrap_or_default();
+    let usage = format!("Usage: {binary} {OPTIONS_USAGE}");
+
+    let options = match parse_options(args) {
+        Ok((options, other_args)) if other_args.is_empty() => options,
+        Ok((_, other_args)) => {
+            eprintln!("Unexpected argument '{}'", other_args[0]);
+            eprintln!("{usage}");
+            return ExitCode::FAILURE;
+        }
+        Err(message) => {
+            eprintln!("{message}");
+            eprintln!("{usage}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if let Err(message) = configure_threads(&options) {
+        eprintln!("{message}");
+        return ExitCode::FAILURE;
+    }
+
+    if let Some(directory) = &options.batch {
+        return match run_batch(day, directory, &options) {
+            Ok(true) => ExitCode::SUCCESS,
+            Ok(false) => ExitCode::FAILURE,
+            Err(message) => {
+                eprintln!("Day {:>2}, {message}", day.number);
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    let source = options.input.clone().unwrap_or(InputSource::Auto);
+    let input = match read_input(day, &options, &source) {
+        Ok(input) => input,
+        Err(message) => {
+            eprintln!("Day {:>2}, {message}", day.number);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match solve(day, &input, &options) {
+        Ok(Some(timings)) => print!("{}", timing::table([(day.number, &timings)])),
+        Ok(None) => {}
+        Err(error) => {
+            eprintln!("Day {:>2}, {}", day.number, error.report(&input.text));
+            return ExitCode::FAILURE;
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+/// Solves a day as selected by the options and prints its answers, either as text or as a JSON
+/// report, checks its input, or shows its simulation or draws it. Returns the timings that
+/// still have to be printed as a table, if any.
+pub fn solve(
+    day: &Day,
+    input: &Input,
+    options: &Options,
+) -> Result<Option<BenchTimings>, SolveError> {
+    if options.check {
+        (day.check)(&input.text)?;
+        println!("Day {:>2}, {} is valid", day.number, input.name);
+        return Ok(None);
+    }
+
+    if let Some(visualize_options) = &options.visualize {
+        let visualize = day.visualize.ok_or(SolveError::NoVisualization)?;
+        visualize(&input.text, &mut Visualizer::new(visualize_options.clone()))?;
+        return Ok(None);
+    }
+
+    if let Some(path) = &options.image {
+        let draw = day.draw.ok_or(SolveError::NoImage)?;
+        draw(&input.text, options.at)?
+            .save(path, IMAGE_SCALE)
+            .map_err(SolveError::SaveImage)?;
+        println!("Day {:>2}, image written to {}", day.number, path.display());
+        return Ok(None);
+    }
+
+    if options.json {
+        // A report always includes durations, so measure a single run unless asked otherwise
+        let runs = options.runs.unwrap_or(1);
+        let (answers, timings) = timing::bench(day, &input.text, options.part, runs)?;
+        println!(
+            "{}",
+            Report::new(
+                day.number,
+                &input.name,
+                answers,
+                &timings,
+                rayon::current_num_threads()
+            )
+            .to_json()
+        );
+        return Ok(None);
+    }
+
+    match options.runs {
+        Some(runs) => {
+            let (answers, timings) = timing::bench(day, &input.text, options.part, runs)?;
+            print_answers(day.number, &answers);
+            Ok(Some(timings))
+        }
+        None => {
+            print_answers(day.number, &(day.run)(&input.text, options.part)?.answers);
+            Ok(None)
+        }
+    }
+}
+
+pub fn print_answers(day: u8, answers: &Answers) {
+    if let Some(answer) = &answers.part_one {
+        println!("Day {day:>2}, part 1: {answer}");
+    }
+
+    if let Some(answer) = &answers.part_two {
+        println!("Day {day:>2}, part 2: {answer}");
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use crate::{
+        cli::{parse_options, Options},
+        input::InputSource,
+        solution::Part,
+        visualize::VisualizeOptions,
+    };
+
+    fn options(args: &[&str]) -> Result<(Options, Vec<String>), String> {
+        parse_options(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn other_arguments_are_passed_through() {
+        let (parsed, other_args) = options(&["3-7", "-p", "2", "all"]).unwrap();
+
+        assert_eq!(parsed.part, Some(Part::Two));
+        assert_eq!(other_args, ["3-7", "all"]);
+    }
+
+    #[test]
+    fn time_is_a_single_run() {
+        let (parsed, _) = options(&["--time", "--input", "-"]).unwrap();
+
+        assert_eq!(parsed.runs, Some(1));
+        assert_eq!(parsed.input, Some(InputSource::Stdin));
+    }
+
+    #[test]
+    fn bench_needs_a_positive_amount_of_runs() {
+        assert_eq!(options(&["--bench", "10"]).unwrap().0.runs, Some(10));
+        assert!(options(&["--bench", "0"]).is_err());
+        assert!(options(&["--bench"]).is_err());
+    }
+
+    #[test]
+    fn json_can_be_combined_with_bench() {
+        let (parsed, _) = options(&["--json", "-b", "3"]).unwrap();
+
+        assert!(parsed.json);
+        assert_eq!(parsed.runs, Some(3));
+    }
+
+    #[test]
+    fn sequential_is_a_single_thread() {
+        assert_eq!(options(&[]).unwrap().0.threads, None);
+        assert_eq!(options(&["--threads", "8"]).unwrap().0.threads, Some(8));
+        assert_eq!(options(&["--sequential"]).unwrap().0.threads, Some(1));
+        assert!(options(&["--threads", "0"]).is_err());
+    }
+
+    #[test]
+    fn check_reads_input_from_stdin() {
+        let (parsed, _) = options(&["--check", "-i", "-"]).unwrap();
+
+        assert!(parsed.check);
+        assert_eq!(parsed.input, Some(InputSource::Stdin));
+    }
+
+    #[test]
+    fn any_visualize_option_enables_it() {
+        assert_eq!(options(&[]).unwrap().0.visualize, None);
+        assert_eq!(
+            options(&["--visualize"]).unwrap().0.visualize,
+            Some(VisualizeOptions::default())
+        );
+
+        let (parsed, _) = options(&["--fps", "30", "--frames", "out"]).unwrap();
+        let visualize = parsed.visualize.unwrap();
+
+        assert_eq!(visualize.frame_rate, 30);
+        assert_eq!(visualize.headless, Some(PathBuf::from("out")));
+        assert!(options(&["--fps", "0"]).is_err());
+    }
+
+    #[test]
+    fn image_needs_a_supported_format() {
+        let (parsed, _) = options(&["--image", "robots.pbm", "--at", "7"]).unwrap();
+
+        assert_eq!(parsed.image, Some(PathBuf::from("robots.pbm")));
+        assert_eq!(parsed.at, Some(7));
+        assert!(options(&["--image", "robots.png"]).is_err());
+    }
+
+    #[test]
+    fn generate_replaces_the_input() {
+        let (parsed, _) = options(&["--generate", "50", "--seed", "3"]).unwrap();
+
+        assert_eq!((parsed.generate, parsed.seed), (Some(50), 3));
+        assert!(options(&["--generate", "0"]).is_err());
+        assert!(options(&["--generate", "50", "--input", "-"]).is_err());
+    }
+
+    #[test]
+    fn batch_replaces_the_input() {
+        let (parsed, _) = options(&["--batch", "inputs/day_13"]).unwrap();
+
+        assert_eq!(parsed.batch, Some(PathBuf::from("inputs/day_13")));
+        assert!(options(&["--batch"]).is_err());
+        assert!(options(&["--batch", "inputs", "-i", "-"]).is_err());
+    }
+}
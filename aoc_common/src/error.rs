@@ -0,0 +1,180 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    iter,
+};
+
+/// A position in the puzzle input, where both the line and column start at 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Location {
+    /// The location of a byte offset into the input, columns are counted in characters.
+    pub fn of_offset(input: &str, offset: usize) -> Self {
+        let before = &input[..offset.min(input.len())];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+/// Input that does not follow the format of a puzzle, as reported by a parser.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyntaxError {
+    pub location: Location,
+    pub message: String,
+    /// Descriptions of what the parser would have accepted instead, if it knows
+    pub expected: Vec<String>,
+}
+
+impl Display for SyntaxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.location, self.message)?;
+
+        if let Some(expected) = self.expected_alternatives() {
+            write!(f, ", expected {expected}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for SyntaxError {}
+
+impl SyntaxError {
+    /// Creates an error at a byte offset into the input. Parsers without a description of what
+    /// they expected (like winnow without context) give an empty message.
+    pub fn new(input: &str, offset: usize, message: impl Display) -> Self {
+        let message = match message.to_string() {
+            message if message.is_empty() => "unexpected input".to_owned(),
+            message => message,
+        };
+
+        Self::at(Location::of_offset(input, offset), message)
+    }
+
+    /// Creates an error at a location that is already known, such as the cell of a grid.
+    pub fn at(location: Location, message: impl Display) -> Self {
+        Self {
+            location,
+            message: message.to_string(),
+            expected: vec![],
+        }
+    }
+
+    pub fn with_expected(mut self, expected: impl IntoIterator<Item = impl Display>) -> Self {
+        self.expected = expected.into_iter().map(|item| item.to_string()).collect();
+        self
+    }
+
+    /// The expected alternatives as a sentence, `a, b or c`.
+    fn expected_alternatives(&self) -> Option<String> {
+        match &self.expected[..] {
+            [] => None,
+            [only] => Some(only.to_owned()),
+            [first @ .., last] => Some(format!("{} or {last}", first.join(", "))),
+        }
+    }
+
+    /// Describes the error over multiple lines, showing the line of the input it is on with a
+    /// marker under the column, followed by what was expected there.
+    pub fn report(&self, input: &str) -> String {
+        let Location { line, column } = self.location;
+        let text = input.lines().nth(line - 1).unwrap_or_default();
+        let gutter = " ".repeat(line.to_string().len());
+
+        let mut report = format!(
+            "{}: {}\n{gutter} |\n{line} | {text}\n{gutter} | {}^",
+            self.location,
+            self.message,
+            " ".repeat(column - 1)
+        );
+
+        if let Some(expected) = self.expected_alternatives() {
+            report.push_str(&format!("\n{gutter} = expected {expected}"));
+        }
+
+        report
+    }
+}
+
+/// The first syntax error in the chain of sources of an error, starting at the error itself.
+pub fn find_syntax_error<'a>(error: &'a (dyn Error + 'static)) -> Option<&'a SyntaxError> {
+    iter::successors(Some(error), |&error| error.source())
+        .find_map(|error| error.downcast_ref::<SyntaxError>())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::error::{find_syntax_error, Location, SyntaxError};
+
+    #[test]
+    fn locates_offsets_on_later_lines() {
+        let input = "12 34\n56 7x\n";
+
+        assert_eq!(
+            Location::of_offset(input, 0),
+            Location { line: 1, column: 1 }
+        );
+        assert_eq!(
+            Location::of_offset(input, 10),
+            Location { line: 2, column: 5 }
+        );
+        assert_eq!(
+            Location::of_offset(input, 12),
+            Location { line: 3, column: 1 }
+        );
+    }
+
+    #[test]
+    fn empty_messages_are_replaced() {
+        let error = SyntaxError::new("ab\ncd", 4, "");
+
+        assert_eq!(error.to_string(), "line 2, column 2: unexpected input");
+    }
+
+    #[test]
+    fn report_points_at_the_column() {
+        let error = SyntaxError::new("47|53\n97|x\n", 9, "invalid page")
+            .with_expected(["a number", "`,`", "`|`"]);
+
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 4: invalid page, expected a number, `,` or `|`"
+        );
+        assert_eq!(
+            error.report("47|53\n97|x\n"),
+            concat!(
+                "line 2, column 4: invalid page\n",
+                "  |\n",
+                "2 | 97|x\n",
+                "  |    ^\n",
+                "  = expected a number, `,` or `|`"
+            )
+        );
+    }
+
+    #[test]
+    fn syntax_error_is_found_among_sources() {
+        #[derive(Debug, thiserror::Error)]
+        #[error("the maze is invalid")]
+        struct MazeError(#[source] SyntaxError);
+
+        let error = MazeError(SyntaxError::new("#", 0, "no start"));
+
+        assert_eq!(find_syntax_error(&error).unwrap().message, "no start");
+        assert!(find_syntax_error(&std::fmt::Error).is_none());
+    }
+}
@@ -0,0 +1,324 @@
+use std::{fmt::Write as _, fs, io, path::Path};
+
+use crate::visualize::Color;
+
+/// A colour as its red, green and blue components.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+}
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb::new(0, 0, 0);
+    pub const WHITE: Rgb = Rgb::new(255, 255, 255);
+
+    pub const fn new(red: u8, green: u8, blue: u8) -> Self {
+        Self { red, green, blue }
+    }
+
+    /// Whether the colour is closer to black than to white, as it would look in grayscale.
+    pub fn is_dark(self) -> bool {
+        let luma = 299 * self.red as u32 + 587 * self.green as u32 + 114 * self.blue as u32;
+        luma < 128 * 1000
+    }
+
+    fn hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.red, self.green, self.blue)
+    }
+
+    /// Converts a hue (in turns), saturation and value to a colour.
+    fn from_hsv(hue: f64, saturation: f64, value: f64) -> Self {
+        let sector = hue.rem_euclid(1.0) * 6.0;
+        let fraction = sector.fract();
+        let (p, q, t) = (
+            value * (1.0 - saturation),
+            value * (1.0 - saturation * fraction),
+            value * (1.0 - saturation * (1.0 - fraction)),
+        );
+
+        let (red, green, blue) = match sector as u8 {
+            0 => (value, t, p),
+            1 => (q, value, p),
+            2 => (p, value, t),
+            3 => (p, q, value),
+            4 => (t, p, value),
+            _ => (value, p, q),
+        };
+
+        let channel = |component: f64| (component * 255.0).round() as u8;
+        Self::new(channel(red), channel(green), channel(blue))
+    }
+}
+
+/// The same colours as the terminal uses for them.
+impl From<Color> for Rgb {
+    fn from(color: Color) -> Self {
+        match color {
+            Color::Default => Rgb::BLACK,
+            Color::Gray => Rgb::new(128, 128, 128),
+            Color::Red => Rgb::new(205, 49, 49),
+            Color::Green => Rgb::new(13, 188, 121),
+            Color::Yellow => Rgb::new(229, 229, 16),
+            Color::Blue => Rgb::new(36, 114, 200),
+            Color::Magenta => Rgb::new(188, 63, 188),
+            Color::Cyan => Rgb::new(17, 168, 205),
+            Color::White => Rgb::WHITE,
+        }
+    }
+}
+
+/// Colours for numbered classes of cells, such as the regions of a garden. Indices past the
+/// end of the palette wrap around.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    colors: Vec<Rgb>,
+}
+
+impl Palette {
+    /// Panics when there are no colours.
+    pub fn new(colors: impl Into<Vec<Rgb>>) -> Self {
+        let colors = colors.into();
+        assert!(!colors.is_empty(), "A palette needs at least one colour");
+
+        Self { colors }
+    }
+
+    /// As many colours as requested (at least one), with the hue stepping by the golden ratio so
+    /// consecutive colours are easy to tell apart.
+    pub fn distinct(count: usize) -> Self {
+        const GOLDEN_RATIO_CONJUGATE: f64 = 0.618_033_988_749_895;
+
+        Self::new(
+            (0..count.max(1))
+                .map(|index| Rgb::from_hsv(index as f64 * GOLDEN_RATIO_CONJUGATE, 0.55, 0.9))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    pub fn color(&self, index: usize) -> Rgb {
+        self.colors[index % self.colors.len()]
+    }
+}
+
+/// The file formats an [`Image`] can be written as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    /// Binary portable pixmap, in full colour
+    Ppm,
+    /// Binary portable bitmap, dark cells become black and all others white
+    Pbm,
+    /// Scalable vector graphics, a square per cell
+    Svg,
+}
+
+impl ImageFormat {
+    /// The format that matches the extension of a file, `None` when it is not supported.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+
+        match extension.as_str() {
+            "ppm" => Some(ImageFormat::Ppm),
+            "pbm" => Some(ImageFormat::Pbm),
+            "svg" => Some(ImageFormat::Svg),
+            _ => None,
+        }
+    }
+}
+
+/// A grid of coloured cells that can be written as an image, every cell becoming a square of
+/// `scale` by `scale` pixels.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    cells: Vec<Rgb>,
+}
+
+impl Image {
+    /// Colours every cell by its `x` and `y`.
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(usize, usize) -> Rgb) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| cell(x, y))
+            .collect();
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn cell(&self, x: usize, y: usize) -> Rgb {
+        self.cells[y * self.width + x]
+    }
+
+    fn rows(&self) -> impl Iterator<Item = &[Rgb]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn encode(&self, format: ImageFormat, scale: usize) -> Vec<u8> {
+        match format {
+            ImageFormat::Ppm => self.to_ppm(scale),
+            ImageFormat::Pbm => self.to_pbm(scale),
+            ImageFormat::Svg => self.to_svg(scale).into_bytes(),
+        }
+    }
+
+    /// Writes the image in the format that matches the extension of the file.
+    pub fn save(&self, path: &Path, scale: usize) -> io::Result<()> {
+        let format = ImageFormat::from_path(path).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "the file should end with .ppm, .pbm or .svg",
+            )
+        })?;
+
+        fs::write(path, self.encode(format, scale))
+    }
+
+    pub fn to_ppm(&self, scale: usize) -> Vec<u8> {
+        let mut bytes =
+            format!("P6\n{} {}\n255\n", self.width * scale, self.height * scale).into_bytes();
+
+        for row in self.rows() {
+            let pixels: Vec<u8> = row
+                .iter()
+                .flat_map(|color| [color.red, color.green, color.blue].repeat(scale))
+                .collect();
+            for _ in 0..scale {
+                bytes.extend(&pixels);
+            }
+        }
+
+        bytes
+    }
+
+    pub fn to_pbm(&self, scale: usize) -> Vec<u8> {
+        let mut bytes =
+            format!("P4\n{} {}\n", self.width * scale, self.height * scale).into_bytes();
+
+        for row in self.rows() {
+            // Every row starts at a new byte, with the first pixel in the highest bit
+            let pixels: Vec<bool> = row
+                .iter()
+                .flat_map(|color| std::iter::repeat_n(color.is_dark(), scale))
+                .collect();
+            let packed: Vec<u8> = pixels
+                .chunks(8)
+                .map(|bits| {
+                    bits.iter()
+                        .enumerate()
+                        .filter(|(_, is_black)| **is_black)
+                        .fold(0, |byte, (bit, _)| byte | 0x80 >> bit)
+                })
+                .collect();
+            for _ in 0..scale {
+                bytes.extend(&packed);
+            }
+        }
+
+        bytes
+    }
+
+    /// Neighbouring cells of the same colour in a row are drawn as a single rectangle.
+    pub fn to_svg(&self, scale: usize) -> String {
+        let (width, height) = (self.width * scale, self.height * scale);
+        let mut svg = format!(
+            concat!(
+                r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{1}" "#,
+                r#"viewBox="0 0 {0} {1}" shape-rendering="crispEdges">"#,
+                "\n"
+            ),
+            width, height
+        );
+
+        for (y, row) in self.rows().enumerate() {
+            let mut x = 0;
+            for run in row.chunk_by(|left, right| left == right) {
+                writeln!(
+                    svg,
+                    r#"<rect x="{}" y="{}" width="{}" height="{scale}" fill="{}"/>"#,
+                    x * scale,
+                    y * scale,
+                    run.len() * scale,
+                    run[0].hex()
+                )
+                .expect("Writing to a string");
+                x += run.len();
+            }
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use crate::image::{Image, ImageFormat, Palette, Rgb};
+
+    const RED: Rgb = Rgb::new(255, 0, 0);
+
+    /// `R.`
+    /// `RR`
+    fn image() -> Image {
+        Image::from_fn(2, 2, |x, y| if x == 0 || y == 1 { RED } else { Rgb::WHITE })
+    }
+
+    #[test]
+    fn ppm_repeats_scaled_pixels() {
+        let ppm = image().to_ppm(2);
+        let (header, pixels) = ppm.split_at("P6\n4 4\n255\n".len());
+
+        assert_eq!(header, b"P6\n4 4\n255\n");
+        assert_eq!(pixels.len(), 4 * 4 * 3);
+        assert_eq!(
+            &pixels[..12],
+            [255, 0, 0, 255, 0, 0, 255, 255, 255, 255, 255, 255]
+        );
+    }
+
+    #[test]
+    fn pbm_packs_dark_pixels_per_row() {
+        // Red is dark enough to become black
+        assert_eq!(image().to_pbm(1), b"P4\n2 2\n\x80\xc0");
+    }
+
+    #[test]
+    fn svg_merges_runs_of_a_colour() {
+        let svg = image().to_svg(10);
+
+        assert_eq!(svg.matches("<rect").count(), 3);
+        assert!(svg.contains(r##"<rect x="0" y="10" width="20" height="10" fill="#ff0000"/>"##));
+    }
+
+    #[test]
+    fn format_follows_the_extension() {
+        assert_eq!(
+            ImageFormat::from_path(Path::new("out/day_14.SVG")),
+            Some(ImageFormat::Svg)
+        );
+        assert_eq!(ImageFormat::from_path(Path::new("day_14.png")), None);
+    }
+
+    #[test]
+    fn distinct_palette_wraps_around() {
+        let palette = Palette::distinct(3);
+
+        assert_ne!(palette.color(0), palette.color(1));
+        assert_eq!(palette.color(4), palette.color(1));
+    }
+}
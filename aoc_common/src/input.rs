@@ -0,0 +1,152 @@
+use std::{
+    fs,
+    io::{self, IsTerminal, Read},
+    path::{Path, PathBuf},
+};
+
+/// Name of the personal puzzle input. These are not part of the repository, so they might be missing.
+const PUZZLE_INPUT_FILE: &str = "input.txt";
+
+/// Name of the file recording the expected answers for the inputs of a day.
+const ANSWERS_FILE: &str = "answers.txt";
+
+/// The input files that live in the directory of a day crate.
+pub struct InputFiles {
+    directory: &'static str,
+    example: &'static str,
+}
+
+impl InputFiles {
+    /// `directory` should be the `CARGO_MANIFEST_DIR` of the day, `example` the file name of
+    /// the (committed) example input within that directory.
+    pub const fn new(directory: &'static str, example: &'static str) -> Self {
+        Self { directory, example }
+    }
+
+    pub fn directory(&self) -> &Path {
+        Path::new(self.directory)
+    }
+
+    pub fn puzzle(&self) -> PathBuf {
+        Path::new(self.directory).join(PUZZLE_INPUT_FILE)
+    }
+
+    pub fn example(&self) -> PathBuf {
+        Path::new(self.directory).join(self.example)
+    }
+
+    pub fn answers(&self) -> PathBuf {
+        Path::new(self.directory).join(ANSWERS_FILE)
+    }
+
+    /// The personal puzzle input when it has been downloaded, the example input otherwise.
+    pub fn default_input(&self) -> PathBuf {
+        let puzzle = self.puzzle();
+        if puzzle.exists() {
+            puzzle
+        } else {
+            self.example()
+        }
+    }
+}
+
+/// Puzzle input together with where it was read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    /// The path of the input file, or `stdin`
+    pub name: String,
+    pub text: String,
+}
+
+impl Input {
+    fn from_file(path: &Path) -> io::Result<Self> {
+        Ok(Self {
+            name: path.display().to_string(),
+            text: fs::read_to_string(path)?,
+        })
+    }
+
+    fn from_stdin(text: String) -> Self {
+        Self {
+            name: "stdin".to_owned(),
+            text,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// Whatever is piped into stdin, or the default input of the day when nothing is piped.
+    Auto,
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// Interprets the value of an `--input` argument, where `-` means stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            Self::Stdin
+        } else {
+            Self::File(arg.into())
+        }
+    }
+
+    pub fn read(&self, files: &InputFiles) -> io::Result<Input> {
+        match self {
+            Self::Auto => {
+                // A closed or empty stdin (like in cron jobs) should not count as input
+                if !io::stdin().is_terminal() {
+                    let piped = read_stdin()?;
+                    if !piped.is_empty() {
+                        return Ok(Input::from_stdin(piped));
+                    }
+                }
+
+                Input::from_file(&files.default_input())
+            }
+            Self::Stdin => read_stdin().map(Input::from_stdin),
+            Self::File(path) => Input::from_file(path),
+        }
+    }
+}
+
+fn read_stdin() -> io::Result<String> {
+    let mut input = String::new();
+    io::stdin().read_to_string(&mut input)?;
+    Ok(input)
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use crate::input::{InputFiles, InputSource};
+
+    const FILES: InputFiles = InputFiles::new(env!("CARGO_MANIFEST_DIR"), "example.txt");
+
+    #[test]
+    fn dash_means_stdin() {
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+    }
+
+    #[test]
+    fn other_values_are_files() {
+        assert_eq!(
+            InputSource::from_arg("day_one/input.txt"),
+            InputSource::File(PathBuf::from("day_one/input.txt"))
+        );
+    }
+
+    #[test]
+    fn falls_back_to_example_without_puzzle_input() {
+        assert!(!FILES.puzzle().exists());
+        assert_eq!(FILES.default_input(), FILES.example());
+    }
+
+    #[test]
+    fn reading_a_missing_file_fails() {
+        let source = InputSource::File(FILES.puzzle());
+        assert!(source.read(&FILES).is_err());
+    }
+}
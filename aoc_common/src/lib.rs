@@ -0,0 +1,12 @@
+pub mod answers;
+pub mod batch;
+pub mod cli;
+pub mod error;
+pub mod image;
+pub mod input;
+pub mod progress;
+pub mod random;
+pub mod report;
+pub mod solution;
+pub mod timing;
+pub mod visualize;
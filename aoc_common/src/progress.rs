@@ -0,0 +1,249 @@
+use std::{
+    fmt,
+    sync::atomic::{AtomicU64, Ordering},
+    time::{Duration, Instant},
+};
+
+/// How often progress is written by default.
+const DEFAULT_INTERVAL: Duration = Duration::from_secs(2);
+
+/// How much work a [`Counter`] collects before adding it to the shared progress.
+const COUNTER_BATCH: u64 = 1 << 12;
+
+/// Progress of a long search that can be shared by many threads (such as rayon workers) without
+/// locking. Work is counted with atomics, and at most one status line per interval is written to
+/// stderr, by whichever thread happens to notice it is due. Searches that finish before the first
+/// interval has passed stay quiet.
+#[derive(Debug)]
+pub struct Progress {
+    label: String,
+    total: Option<u64>,
+    interval: Duration,
+    is_hidden: bool,
+    started: Instant,
+    done: AtomicU64,
+    /// Milliseconds after the start at which the next status is due
+    next_report: AtomicU64,
+    reports: AtomicU64,
+}
+
+impl Progress {
+    /// Progress of `label`, such as "values checked", out of `total` when it is known.
+    pub fn new(label: impl Into<String>, total: Option<u64>) -> Self {
+        Self {
+            label: label.into(),
+            total,
+            interval: DEFAULT_INTERVAL,
+            is_hidden: false,
+            started: Instant::now(),
+            done: AtomicU64::new(0),
+            next_report: AtomicU64::new(DEFAULT_INTERVAL.as_millis() as u64),
+            reports: AtomicU64::new(0),
+        }
+    }
+
+    /// Writes a status at most once per interval, the first one after a full interval.
+    pub fn with_interval(self, interval: Duration) -> Self {
+        Self {
+            interval,
+            next_report: AtomicU64::new(interval.as_millis() as u64),
+            ..self
+        }
+    }
+
+    /// Keeps counting, but never writes anything.
+    pub fn hidden(self) -> Self {
+        Self {
+            is_hidden: true,
+            ..self
+        }
+    }
+
+    /// Adds work that has been done, writing the status when it is due.
+    pub fn add(&self, amount: u64) {
+        self.done.fetch_add(amount, Ordering::Relaxed);
+
+        let elapsed = self.started.elapsed().as_millis() as u64;
+        let due = self.next_report.load(Ordering::Relaxed);
+        if elapsed < due {
+            return;
+        }
+
+        // Only the thread that moves the deadline reports, the others just carry on
+        let next = elapsed + self.interval.as_millis() as u64;
+        if self
+            .next_report
+            .compare_exchange(due, next, Ordering::Relaxed, Ordering::Relaxed)
+            .is_ok()
+        {
+            self.reports.fetch_add(1, Ordering::Relaxed);
+            self.write(&self.status());
+        }
+    }
+
+    /// A counter for a single thread, which adds its work in batches to keep the threads from
+    /// fighting over the shared counter. Use it with `map_init` or `for_each_init` of rayon.
+    pub fn counter(&self) -> Counter<'_> {
+        Counter {
+            progress: self,
+            pending: 0,
+        }
+    }
+
+    pub fn done(&self) -> u64 {
+        self.done.load(Ordering::Relaxed)
+    }
+
+    /// A line like `values checked: 1200/4800 (25.0%), 400/s after 3.0s`.
+    pub fn status(&self) -> String {
+        let done = self.done();
+        let elapsed = self.started.elapsed();
+
+        let amount = match self.total {
+            Some(total) if total > 0 => {
+                format!(
+                    "{done}/{total} ({:.1}%)",
+                    done as f64 * 100.0 / total as f64
+                )
+            }
+            _ => done.to_string(),
+        };
+
+        format!(
+            "{}: {amount}, {}/s after {:.1?}",
+            self.label,
+            rate(done, elapsed),
+            elapsed
+        )
+    }
+
+    /// Stops measuring, writing a summary when any status has been written.
+    pub fn finish(&self) -> Summary {
+        let summary = Summary {
+            label: self.label.clone(),
+            done: self.done(),
+            elapsed: self.started.elapsed(),
+            reports: self.reports.load(Ordering::Relaxed),
+        };
+
+        if summary.reports > 0 {
+            self.write(&summary.to_string());
+        }
+        summary
+    }
+
+    fn write(&self, line: &str) {
+        if !self.is_hidden {
+            eprintln!("{line}");
+        }
+    }
+}
+
+/// Work per second, rounded down.
+fn rate(done: u64, elapsed: Duration) -> u64 {
+    match elapsed.as_secs_f64() {
+        seconds if seconds > 0.0 => (done as f64 / seconds) as u64,
+        _ => 0,
+    }
+}
+
+/// Counts the work of a single thread, see [`Progress::counter`]. Whatever has not been added yet
+/// is added when the counter is dropped.
+#[derive(Debug)]
+pub struct Counter<'a> {
+    progress: &'a Progress,
+    pending: u64,
+}
+
+impl Counter<'_> {
+    pub fn tick(&mut self) {
+        self.pending += 1;
+        if self.pending == COUNTER_BATCH {
+            self.flush();
+        }
+    }
+
+    pub fn flush(&mut self) {
+        if self.pending > 0 {
+            self.progress.add(self.pending);
+            self.pending = 0;
+        }
+    }
+}
+
+impl Drop for Counter<'_> {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
+
+/// What a search did, once it has finished.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Summary {
+    pub label: String,
+    pub done: u64,
+    pub elapsed: Duration,
+    /// How many status lines were written along the way
+    pub reports: u64,
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} in total, {:.1?} at {}/s",
+            self.label,
+            self.done,
+            self.elapsed,
+            rate(self.done, self.elapsed)
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use rayon::prelude::*;
+
+    use crate::progress::Progress;
+
+    #[test]
+    fn counts_the_work_of_all_threads() {
+        let progress = Progress::new("values checked", None).hidden();
+
+        (0..10_000)
+            .into_par_iter()
+            .for_each_init(|| progress.counter(), |counter, _| counter.tick());
+
+        assert_eq!(progress.finish().done, 10_000);
+    }
+
+    #[test]
+    fn reports_are_rate_limited() {
+        let slow = Progress::new("slow", None)
+            .with_interval(Duration::from_secs(3600))
+            .hidden();
+        let fast = Progress::new("fast", None)
+            .with_interval(Duration::ZERO)
+            .hidden();
+
+        for _ in 0..3 {
+            slow.add(1);
+            fast.add(1);
+        }
+
+        assert_eq!(slow.finish().reports, 0);
+        assert_eq!(fast.finish().reports, 3);
+    }
+
+    #[test]
+    fn status_shows_the_share_of_the_total() {
+        let progress = Progress::new("machines solved", Some(8)).hidden();
+        progress.add(2);
+
+        assert!(progress
+            .status()
+            .starts_with("machines solved: 2/8 (25.0%), "));
+    }
+}
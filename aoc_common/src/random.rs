@@ -0,0 +1,114 @@
+use std::ops::Range;
+
+/// A small, seeded pseudo random number generator (SplitMix64) for generating puzzle inputs.
+/// The same seed always gives the same numbers, so a generated input can be reproduced from
+/// its seed. Not suited for anything that has to be unpredictable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..bound`, panics when the bound is zero.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "There is no number below zero");
+
+        // Widening multiplication maps the random bits evenly enough onto the bound
+        ((self.next_u64() as u128 * bound as u128) >> 64) as u64
+    }
+
+    /// A number in the range, panics when the range is empty.
+    pub fn range(&mut self, range: Range<i64>) -> i64 {
+        assert!(!range.is_empty(), "There is no number in an empty range");
+
+        let size = range.end.abs_diff(range.start);
+        range.start.wrapping_add(self.below(size) as i64)
+    }
+
+    /// An index into a slice of the given length, panics when it is empty.
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    /// Whether an event with the given probability (between 0 and 1) happens.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        // The top 53 bits, as many as the mantissa of a float holds
+        let fraction = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        fraction < probability
+    }
+
+    /// One of the items, panics when there are none.
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        // Fisher-Yates, swapping every item with one that is not yet in its place
+        for index in (1..items.len()).rev() {
+            items.swap(index, self.index(index + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::random::Rng;
+
+    #[test]
+    fn same_seed_same_numbers() {
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+            [rng.next_u64(), rng.next_u64(), rng.next_u64()]
+        };
+
+        assert_eq!(numbers(7), numbers(7));
+        assert_ne!(numbers(7), numbers(8));
+    }
+
+    #[test]
+    fn numbers_stay_in_range() {
+        let mut rng = Rng::new(1);
+
+        for _ in 0..1000 {
+            assert!((-3..4).contains(&rng.range(-3..4)));
+            assert!(rng.below(10) < 10);
+        }
+
+        assert_eq!(rng.range(i64::MIN..i64::MIN + 1), i64::MIN);
+    }
+
+    #[test]
+    fn every_value_is_possible() {
+        let mut rng = Rng::new(2);
+        let mut seen = [false; 6];
+
+        for _ in 0..200 {
+            seen[rng.index(6)] = true;
+        }
+
+        assert_eq!(seen, [true; 6]);
+    }
+
+    #[test]
+    fn shuffle_keeps_all_items() {
+        let mut items: Vec<_> = (0..20).collect();
+        Rng::new(3).shuffle(&mut items);
+
+        assert_ne!(items, (0..20).collect::<Vec<_>>());
+        items.sort();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,118 @@
+use serde::Serialize;
+
+use crate::{
+    solution::Answers,
+    timing::{BenchTimings, Spread},
+};
+
+/// The outcome of solving a day in a machine readable form, see [`Report::to_json`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Report {
+    pub day: u8,
+    /// Where the input was read from, a file path or `stdin`
+    pub input: String,
+    pub answers: Answers,
+    pub runs: usize,
+    /// The number of threads parallel parts could use
+    pub threads: usize,
+    pub durations: Durations,
+}
+
+/// The measured durations of every phase, a part that was not run has no durations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Durations {
+    pub parse: Nanos,
+    pub part_one: Option<Nanos>,
+    pub part_two: Option<Nanos>,
+}
+
+/// A [`Spread`] in whole nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Nanos {
+    pub min_ns: u128,
+    pub median_ns: u128,
+    pub max_ns: u128,
+}
+
+impl From<Spread> for Nanos {
+    fn from(spread: Spread) -> Self {
+        Self {
+            min_ns: spread.min.as_nanos(),
+            median_ns: spread.median.as_nanos(),
+            max_ns: spread.max.as_nanos(),
+        }
+    }
+}
+
+impl Report {
+    pub fn new(
+        day: u8,
+        input: &str,
+        answers: Answers,
+        timings: &BenchTimings,
+        threads: usize,
+    ) -> Self {
+        Self {
+            day,
+            input: input.to_owned(),
+            answers,
+            runs: timings.runs,
+            threads,
+            durations: Durations {
+                parse: timings.parse.into(),
+                part_one: timings.part_one.map(Nanos::from),
+                part_two: timings.part_two.map(Nanos::from),
+            },
+        }
+    }
+
+    /// Formats the report as a single line of JSON, so multiple days can be written as
+    /// [JSON Lines](https://jsonlines.org).
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("A report only contains plain data")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use crate::{
+        report::Report,
+        solution::{Answers, Timings},
+        timing::BenchTimings,
+    };
+
+    #[test]
+    fn serializes_answers_and_durations() {
+        let timings = Timings {
+            parse: Duration::from_micros(3),
+            part_one: None,
+            part_two: Some(Duration::from_nanos(250)),
+        };
+
+        let answers = Answers {
+            part_one: None,
+            part_two: Some("42".to_owned()),
+        };
+
+        let report = Report::new(
+            9,
+            "day_nine/input.txt",
+            answers,
+            &BenchTimings::of(&[timings]).unwrap(),
+            4,
+        );
+
+        assert_eq!(
+            report.to_json(),
+            concat!(
+                r#"{"day":9,"input":"day_nine/input.txt","#,
+                r#""answers":{"part_one":null,"part_two":"42"},"runs":1,"threads":4,"#,
+                r#""durations":{"parse":{"min_ns":3000,"median_ns":3000,"max_ns":3000},"#,
+                r#""part_one":null,"#,
+                r#""part_two":{"min_ns":250,"median_ns":250,"max_ns":250}}}"#,
+            )
+        );
+    }
+}
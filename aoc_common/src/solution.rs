@@ -0,0 +1,321 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    io,
+    time::{Duration, Instant},
+};
+
+use serde::Serialize;
+
+use crate::{
+    error::find_syntax_error, image::Image, input::InputFiles, random::Rng, visualize::Visualizer,
+};
+
+/// The solution of a single puzzle day. The input is parsed once and then shared by both parts.
+///
+/// Invalid input is reported through `Error` instead of panicking, days that cannot fail use
+/// [`std::convert::Infallible`].
+pub trait Solution {
+    type Input;
+    type PartOne: Display;
+    type PartTwo: Display;
+    type Error: Error + Send + Sync + 'static;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error>;
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne, Self::Error>;
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo, Self::Error>;
+}
+
+/// A day that can show the simulation behind its puzzle, frame by frame.
+pub trait Visualize: Solution {
+    fn visualize(input: &Self::Input, visualizer: &mut Visualizer) -> io::Result<()>;
+}
+
+/// A day that can generate valid inputs of its puzzle, to test and stress the solution with.
+pub trait Generate: Solution {
+    /// Generates an input of roughly the given size, such as the number of lines or the width of
+    /// a grid. What the size means is up to the day, but larger sizes give larger inputs.
+    fn generate(rng: &mut Rng, size: usize) -> String;
+}
+
+/// A day that can draw the state of its puzzle as an image.
+pub trait Draw: Solution {
+    /// Draws the state at a moment of the simulation, such as a second or a step. Days without
+    /// moments, or with a sensible default, ignore or replace `None`.
+    fn draw(input: &Self::Input, moment: Option<usize>) -> Result<Image, Self::Error>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// Why a day could not be solved, wrapping the error of the day itself.
+#[derive(Debug, thiserror::Error)]
+pub enum SolveError {
+    #[error("invalid input, {0}")]
+    Parse(Box<dyn Error + Send + Sync>),
+    #[error("unable to solve part {0}, {1}")]
+    Part(Part, Box<dyn Error + Send + Sync>),
+    #[error("there is nothing to visualize for this day")]
+    NoVisualization,
+    #[error("unable to show a frame, {0}")]
+    Visualize(io::Error),
+    #[error("there is no input generator for this day")]
+    NoGenerator,
+    #[error("there is no image for this day")]
+    NoImage,
+    #[error("unable to draw the image, {0}")]
+    Draw(Box<dyn Error + Send + Sync>),
+    #[error("unable to write the image, {0}")]
+    SaveImage(io::Error),
+}
+
+impl SolveError {
+    /// Describes the error like its `Display`, but shows where a syntax error is in the input.
+    pub fn report(&self, input: &str) -> String {
+        match self {
+            SolveError::Parse(error) => match find_syntax_error(error.as_ref()) {
+                Some(syntax_error) => format!("invalid input, {}", syntax_error.report(input)),
+                None => self.to_string(),
+            },
+            _ => self.to_string(),
+        }
+    }
+}
+
+/// The answers of a day formatted for display, a part that was not run has no answer.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct Answers {
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+/// How long each phase of solving a day took, a part that was not run has no duration.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part_one: Option<Duration>,
+    pub part_two: Option<Duration>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    pub answers: Answers,
+    pub timings: Timings,
+}
+
+/// A puzzle day with its solution erased, so days with different input and answer types can be
+/// run in the same way.
+pub struct Day {
+    pub number: u8,
+    pub input_files: &'static InputFiles,
+    pub run: fn(&str, Option<Part>) -> Result<Run, SolveError>,
+    /// Only parses the input, to check whether it is valid
+    pub check: fn(&str) -> Result<(), SolveError>,
+    pub visualize: Option<VisualizeFn>,
+    pub draw: Option<DrawFn>,
+    /// Generates an input of a size from a seed
+    pub generate: Option<fn(u64, usize) -> String>,
+}
+
+impl Day {
+    pub const fn new<S: Solution>(number: u8, input_files: &'static InputFiles) -> Self {
+        Self {
+            number,
+            input_files,
+            run: run::<S>,
+            check: check::<S>,
+            visualize: None,
+            draw: None,
+            generate: None,
+        }
+    }
+
+    pub const fn with_visualization<S: Visualize>(self) -> Self {
+        Self {
+            visualize: Some(visualize::<S>),
+            ..self
+        }
+    }
+
+    pub const fn with_image<S: Draw>(self) -> Self {
+        Self {
+            draw: Some(draw::<S>),
+            ..self
+        }
+    }
+
+    pub const fn with_generator<S: Generate>(self) -> Self {
+        Self {
+            generate: Some(generate::<S>),
+            ..self
+        }
+    }
+}
+
+/// Generates an input with a generator seeded with `seed`.
+pub fn generate<S: Generate>(seed: u64, size: usize) -> String {
+    S::generate(&mut Rng::new(seed), size)
+}
+
+/// Parses the input without solving anything.
+pub fn check<S: Solution>(input: &str) -> Result<(), SolveError> {
+    S::parse(input).map_err(|error| SolveError::Parse(error.into()))?;
+    Ok(())
+}
+
+/// Parses the input and solves the given part of a day, or both parts when no part is given.
+pub fn run<S: Solution>(input: &str, part: Option<Part>) -> Result<Run, SolveError> {
+    let start = Instant::now();
+    let input = S::parse(input).map_err(|error| SolveError::Parse(error.into()))?;
+    let parse = start.elapsed();
+
+    let (part_one, part_one_duration) =
+        timed_if(part != Some(Part::Two), Part::One, || S::part_one(&input))?;
+    let (part_two, part_two_duration) =
+        timed_if(part != Some(Part::One), Part::Two, || S::part_two(&input))?;
+
+    Ok(Run {
+        answers: Answers { part_one, part_two },
+        timings: Timings {
+            parse,
+            part_one: part_one_duration,
+            part_two: part_two_duration,
+        },
+    })
+}
+
+/// Shows the simulation of a day for the given input, see [`visualize`].
+pub type VisualizeFn = fn(&str, &mut Visualizer) -> Result<(), SolveError>;
+
+/// Parses the input and shows the simulation of a day.
+pub fn visualize<S: Visualize>(input: &str, visualizer: &mut Visualizer) -> Result<(), SolveError> {
+    let input = S::parse(input).map_err(|error| SolveError::Parse(error.into()))?;
+    S::visualize(&input, visualizer).map_err(SolveError::Visualize)
+}
+
+/// Draws the state of a day for the given input, see [`draw`].
+pub type DrawFn = fn(&str, Option<usize>) -> Result<Image, SolveError>;
+
+/// Parses the input and draws the state of a day.
+pub fn draw<S: Draw>(input: &str, moment: Option<usize>) -> Result<Image, SolveError> {
+    let input = S::parse(input).map_err(|error| SolveError::Parse(error.into()))?;
+    S::draw(&input, moment).map_err(|error| SolveError::Draw(error.into()))
+}
+
+/// Solves a part when it should be run, only measuring the time it takes to find the answer.
+fn timed_if<T: Display, E: Error + Send + Sync + 'static>(
+    should_run: bool,
+    part: Part,
+    solve: impl FnOnce() -> Result<T, E>,
+) -> Result<(Option<String>, Option<Duration>), SolveError> {
+    if !should_run {
+        return Ok((None, None));
+    }
+
+    let start = Instant::now();
+    let answer = solve().map_err(|error| SolveError::Part(part, error.into()))?;
+    let duration = start.elapsed();
+
+    Ok((Some(answer.to_string()), Some(duration)))
+}
+
+#[cfg(test)]
+mod test {
+    use std::num::ParseIntError;
+
+    use crate::solution::{run, Answers, Part, Solution, SolveError};
+
+    struct Sum;
+
+    #[derive(Debug, thiserror::Error)]
+    enum SumError {
+        #[error(transparent)]
+        Number(#[from] ParseIntError),
+        #[error("the product overflows")]
+        Overflow,
+    }
+
+    impl Solution for Sum {
+        type Input = Vec<u32>;
+        type PartOne = u32;
+        type PartTwo = u32;
+        type Error = SumError;
+
+        fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+            Ok(input
+                .split(',')
+                .map(|n| n.parse())
+                .collect::<Result<_, _>>()?)
+        }
+
+        fn part_one(input: &Self::Input) -> Result<Self::PartOne, Self::Error> {
+            Ok(input.iter().sum())
+        }
+
+        fn part_two(input: &Self::Input) -> Result<Self::PartTwo, Self::Error> {
+            input
+                .iter()
+                .try_fold(1u32, |product, n| product.checked_mul(*n))
+                .ok_or(SumError::Overflow)
+        }
+    }
+
+    #[test]
+    fn solves_both_parts() {
+        let expected = Answers {
+            part_one: Some("9".to_owned()),
+            part_two: Some("24".to_owned()),
+        };
+
+        let run = run::<Sum>("2,3,4", None).unwrap();
+
+        assert_eq!(run.answers, expected);
+        assert!(run.timings.part_one.is_some() && run.timings.part_two.is_some());
+    }
+
+    #[test]
+    fn solves_a_single_part() {
+        let expected = Answers {
+            part_one: None,
+            part_two: Some("24".to_owned()),
+        };
+
+        let run = run::<Sum>("2,3,4", Some(Part::Two)).unwrap();
+
+        assert_eq!(run.answers, expected);
+        assert_eq!(run.timings.part_one, None);
+    }
+
+    #[test]
+    fn invalid_input_is_an_error() {
+        let error = run::<Sum>("2,x", None).unwrap_err();
+
+        assert!(matches!(error, SolveError::Parse(_)));
+        assert!(error.to_string().starts_with("invalid input, "));
+    }
+
+    #[test]
+    fn failing_part_is_reported() {
+        let error = run::<Sum>("65536,65536", None).unwrap_err();
+
+        assert!(matches!(error, SolveError::Part(Part::Two, _)));
+        assert_eq!(
+            error.to_string(),
+            "unable to solve part 2, the product overflows"
+        );
+    }
+}
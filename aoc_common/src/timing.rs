@@ -0,0 +1,157 @@
+use std::time::Duration;
+
+use crate::solution::{Answers, Day, Part, SolveError, Timings};
+
+/// The fastest, median and slowest duration of repeated measurements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Spread {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Spread {
+    /// Returns `None` when there are no durations to take the spread of.
+    pub fn of(durations: impl IntoIterator<Item = Duration>) -> Option<Self> {
+        let mut durations: Vec<_> = durations.into_iter().collect();
+        durations.sort();
+
+        let middle = durations.len() / 2;
+        let median = if durations.len() % 2 == 0 {
+            (*durations.get(middle.checked_sub(1)?)? + durations[middle]) / 2
+        } else {
+            durations[middle]
+        };
+
+        Some(Self {
+            min: *durations.first()?,
+            median,
+            max: *durations.last()?,
+        })
+    }
+}
+
+/// The spread of every phase of a day that was run multiple times.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchTimings {
+    pub runs: usize,
+    pub parse: Spread,
+    pub part_one: Option<Spread>,
+    pub part_two: Option<Spread>,
+}
+
+impl BenchTimings {
+    /// Combines the timings of separate runs, returning `None` when there are no runs.
+    pub fn of(timings: &[Timings]) -> Option<Self> {
+        Some(Self {
+            runs: timings.len(),
+            parse: Spread::of(timings.iter().map(|timing| timing.parse))?,
+            part_one: Spread::of(timings.iter().filter_map(|timing| timing.part_one)),
+            part_two: Spread::of(timings.iter().filter_map(|timing| timing.part_two)),
+        })
+    }
+
+    /// The phases that have been measured, labeled for display.
+    pub fn phases(&self) -> impl Iterator<Item = (&'static str, Spread)> {
+        [
+            ("parse", Some(self.parse)),
+            ("part 1", self.part_one),
+            ("part 2", self.part_two),
+        ]
+        .into_iter()
+        .filter_map(|(phase, spread)| spread.map(|spread| (phase, spread)))
+    }
+}
+
+/// Solves a day `runs` times (at least once), returning the answers of the last run.
+pub fn bench(
+    day: &Day,
+    input: &str,
+    part: Option<Part>,
+    runs: usize,
+) -> Result<(Answers, BenchTimings), SolveError> {
+    let mut answers = Answers::default();
+    let mut timings = Vec::with_capacity(runs);
+
+    for _ in 0..runs.max(1) {
+        let run = (day.run)(input, part)?;
+        answers = run.answers;
+        timings.push(run.timings);
+    }
+
+    let timings = BenchTimings::of(&timings).expect("There is at least one run");
+    Ok((answers, timings))
+}
+
+/// Formats the timings of one or more days as a table, with a row for every phase.
+pub fn table<'a>(days: impl IntoIterator<Item = (u8, &'a BenchTimings)>) -> String {
+    let mut table = format!(
+        "{:>3}  {:<6} {:>10} {:>10} {:>10}\n",
+        "Day", "Phase", "Min", "Median", "Max"
+    );
+
+    for (day, timings) in days {
+        for (phase, spread) in timings.phases() {
+            table.push_str(&format!(
+                "{day:>3}  {phase:<6} {:>10} {:>10} {:>10}\n",
+                format!("{:.1?}", spread.min),
+                format!("{:.1?}", spread.median),
+                format!("{:.1?}", spread.max),
+            ));
+        }
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use crate::{
+        solution::Timings,
+        timing::{table, BenchTimings, Spread},
+    };
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().copied().map(Duration::from_millis).collect()
+    }
+
+    #[test]
+    fn spread_of_an_odd_amount() {
+        let spread = Spread::of(millis(&[5, 1, 3])).unwrap();
+
+        assert_eq!(spread.min, Duration::from_millis(1));
+        assert_eq!(spread.median, Duration::from_millis(3));
+        assert_eq!(spread.max, Duration::from_millis(5));
+    }
+
+    #[test]
+    fn median_of_an_even_amount_is_the_mean_of_the_middle() {
+        let spread = Spread::of(millis(&[4, 1, 2, 8])).unwrap();
+
+        assert_eq!(spread.median, Duration::from_millis(3));
+    }
+
+    #[test]
+    fn no_spread_without_durations() {
+        assert_eq!(Spread::of(vec![]), None);
+    }
+
+    #[test]
+    fn parts_that_did_not_run_are_left_out() {
+        let timings = Timings {
+            parse: Duration::from_millis(1),
+            part_one: Some(Duration::from_millis(2)),
+            part_two: None,
+        };
+
+        let bench = BenchTimings::of(&[timings, timings]).unwrap();
+        let table = table([(7, &bench)]);
+
+        assert_eq!(bench.runs, 2);
+        assert_eq!(bench.part_two, None);
+        assert_eq!(table.lines().count(), 3);
+        assert!(table.contains("  7  part 1      2.0ms      2.0ms      2.0ms"));
+    }
+}
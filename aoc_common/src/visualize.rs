@@ -0,0 +1,252 @@
+use std::{
+    fmt::Write as _,
+    fs,
+    io::{self, IsTerminal, Write},
+    path::PathBuf,
+    thread,
+    time::{Duration, Instant},
+};
+
+/// The colours of the basic ANSI palette that every terminal supports.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Color {
+    #[default]
+    Default,
+    Gray,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+impl Color {
+    /// The SGR parameter selecting this colour as foreground.
+    fn ansi_code(self) -> u8 {
+        match self {
+            Color::Default => 39,
+            Color::Gray => 90,
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+            Color::White => 97,
+        }
+    }
+}
+
+/// How a single cell of a grid is drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub symbol: char,
+    pub color: Color,
+}
+
+impl Cell {
+    pub const fn new(symbol: char, color: Color) -> Self {
+        Self { symbol, color }
+    }
+
+    pub const fn plain(symbol: char) -> Self {
+        Self::new(symbol, Color::Default)
+    }
+}
+
+/// A single picture of a simulation: rows of cells, optionally with a caption underneath.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Frame {
+    rows: Vec<Vec<Cell>>,
+    caption: Option<String>,
+}
+
+impl Frame {
+    pub fn from_rows<R: IntoIterator<Item = Cell>>(rows: impl IntoIterator<Item = R>) -> Self {
+        Self {
+            rows: rows
+                .into_iter()
+                .map(|row| row.into_iter().collect())
+                .collect(),
+            caption: None,
+        }
+    }
+
+    pub fn with_caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// The frame as text without colours, every row (and the caption) ending with a newline.
+    pub fn to_plain(&self) -> String {
+        let mut text = String::new();
+
+        for row in &self.rows {
+            text.extend(row.iter().map(|cell| cell.symbol));
+            text.push('\n');
+        }
+
+        if let Some(caption) = &self.caption {
+            text.push_str(caption);
+            text.push('\n');
+        }
+
+        text
+    }
+
+    /// The frame as text with ANSI escape codes, only switching colours between runs of cells.
+    pub fn to_ansi(&self) -> String {
+        let mut text = String::new();
+
+        for row in &self.rows {
+            let mut current = Color::Default;
+            for cell in row {
+                if cell.color != current {
+                    current = cell.color;
+                    write!(text, "\x1b[{}m", current.ansi_code()).expect("Writing to a string");
+                }
+                text.push(cell.symbol);
+            }
+
+            if current != Color::Default {
+                text.push_str("\x1b[0m");
+            }
+            text.push('\n');
+        }
+
+        if let Some(caption) = &self.caption {
+            text.push_str(caption);
+            text.push('\n');
+        }
+
+        text
+    }
+}
+
+/// How frames are shown, as selected on the command line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VisualizeOptions {
+    /// How many frames to show per second in the terminal
+    pub frame_rate: u32,
+    /// Wait for enter after every frame instead of using the frame rate
+    pub step: bool,
+    /// Write every frame to a numbered file in this directory instead of the terminal
+    pub headless: Option<PathBuf>,
+}
+
+impl Default for VisualizeOptions {
+    fn default() -> Self {
+        Self {
+            frame_rate: 10,
+            step: false,
+            headless: None,
+        }
+    }
+}
+
+/// Shows the frames of a simulation one after another, either animated in the terminal or
+/// written to files.
+pub struct Visualizer {
+    options: VisualizeOptions,
+    frames: usize,
+    last_shown: Option<Instant>,
+}
+
+impl Visualizer {
+    pub fn new(options: VisualizeOptions) -> Self {
+        Self {
+            options,
+            frames: 0,
+            last_shown: None,
+        }
+    }
+
+    /// How many frames have been shown so far.
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+
+    pub fn show(&mut self, frame: &Frame) -> io::Result<()> {
+        self.frames += 1;
+
+        match &self.options.headless {
+            Some(directory) => {
+                fs::create_dir_all(directory)?;
+                fs::write(
+                    directory.join(format!("frame_{:05}.txt", self.frames)),
+                    frame.to_plain(),
+                )
+            }
+            None => self.show_in_terminal(frame),
+        }
+    }
+
+    fn show_in_terminal(&mut self, frame: &Frame) -> io::Result<()> {
+        let mut stdout = io::stdout().lock();
+
+        if stdout.is_terminal() {
+            // Move to the top left and clear the screen, so frames replace each other
+            write!(stdout, "\x1b[H\x1b[2J{}", frame.to_ansi())?;
+        } else {
+            write!(stdout, "{}", frame.to_plain())?;
+        }
+        stdout.flush()?;
+
+        if self.options.step {
+            io::stdin().read_line(&mut String::new())?;
+        } else if let Some(last_shown) = self.last_shown {
+            let frame_time = Duration::from_secs(1) / self.options.frame_rate.max(1);
+            thread::sleep(frame_time.saturating_sub(last_shown.elapsed()));
+        }
+
+        self.last_shown = Some(Instant::now());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use crate::visualize::{Cell, Color, Frame, VisualizeOptions, Visualizer};
+
+    fn frame() -> Frame {
+        Frame::from_rows([
+            [Cell::new('#', Color::Gray), Cell::plain('.')],
+            [Cell::new('@', Color::Red), Cell::new('@', Color::Red)],
+        ])
+    }
+
+    #[test]
+    fn plain_frames_have_no_escape_codes() {
+        assert_eq!(
+            frame().with_caption("step 1").to_plain(),
+            "#.\n@@\nstep 1\n"
+        );
+    }
+
+    #[test]
+    fn colours_only_change_between_runs() {
+        assert_eq!(frame().to_ansi(), "\x1b[90m#\x1b[39m.\n\x1b[31m@@\x1b[0m\n");
+    }
+
+    #[test]
+    fn headless_frames_are_numbered_files() {
+        let directory = std::env::temp_dir().join(format!("aoc_frames_{}", std::process::id()));
+        let mut visualizer = Visualizer::new(VisualizeOptions {
+            headless: Some(directory.clone()),
+            ..VisualizeOptions::default()
+        });
+
+        visualizer.show(&frame()).unwrap();
+        visualizer.show(&frame().with_caption("last")).unwrap();
+
+        let last = fs::read_to_string(directory.join("frame_00002.txt")).unwrap();
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(visualizer.frames(), 2);
+        assert_eq!(last, "#.\n@@\nlast\n");
+    }
+}
@@ -0,0 +1,218 @@
+use std::str::FromStr;
+
+use glam::IVec2;
+
+/// A compass direction on a grid where north is up, so going north decreases `y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("'{0}' is not a direction, expected one of ^>v< or N, NE, E, SE, S, SW, W, NW")]
+pub struct ParseDirectionError(String);
+
+impl Direction {
+    /// The four directions along the axes, clockwise starting at north.
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// Only the diagonal directions, clockwise starting at north east.
+    pub const DIAGONAL: [Direction; 4] = [
+        Direction::NorthEast,
+        Direction::SouthEast,
+        Direction::SouthWest,
+        Direction::NorthWest,
+    ];
+
+    /// All eight directions, clockwise starting at north.
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    /// The step to take on a grid to move one cell in this direction.
+    pub const fn vector(self) -> IVec2 {
+        match self {
+            Direction::North => IVec2::new(0, -1),
+            Direction::NorthEast => IVec2::new(1, -1),
+            Direction::East => IVec2::new(1, 0),
+            Direction::SouthEast => IVec2::new(1, 1),
+            Direction::South => IVec2::new(0, 1),
+            Direction::SouthWest => IVec2::new(-1, 1),
+            Direction::West => IVec2::new(-1, 0),
+            Direction::NorthWest => IVec2::new(-1, -1),
+        }
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self.eighths() % 2 == 1
+    }
+
+    /// Turns a quarter (90 degrees) to the right.
+    pub fn clockwise(self) -> Self {
+        self.rotate_eighths(2)
+    }
+
+    /// Turns a quarter (90 degrees) to the left.
+    pub fn counter_clockwise(self) -> Self {
+        self.rotate_eighths(-2)
+    }
+
+    /// Turns an eighth (45 degrees) to the right, like from north to north east.
+    pub fn clockwise_eighth(self) -> Self {
+        self.rotate_eighths(1)
+    }
+
+    /// Turns an eighth (45 degrees) to the left, like from north to north west.
+    pub fn counter_clockwise_eighth(self) -> Self {
+        self.rotate_eighths(-1)
+    }
+
+    pub fn opposite(self) -> Self {
+        self.rotate_eighths(4)
+    }
+
+    /// The least amount of quarter turns (either way) needed to face the other direction,
+    /// where a turn between a diagonal and an axis counts as a whole quarter turn.
+    pub fn quarter_turns_to(self, other: Direction) -> u32 {
+        self.eighth_turns_to(other).div_ceil(2)
+    }
+
+    /// The least amount of eighth turns (either way) needed to face the other direction.
+    pub fn eighth_turns_to(self, other: Direction) -> u32 {
+        let difference = self.eighths().abs_diff(other.eighths());
+        difference.min(8 - difference)
+    }
+
+    fn eighths(self) -> u32 {
+        self as u32
+    }
+
+    fn rotate_eighths(self, eighths: i32) -> Self {
+        Self::ALL[(self.eighths() as i32 + eighths).rem_euclid(8) as usize]
+    }
+}
+
+impl From<Direction> for IVec2 {
+    fn from(direction: Direction) -> Self {
+        direction.vector()
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = ParseDirectionError;
+
+    /// Accepts arrows (`^>v<`) as well as the first letter of a compass direction (`NESW`).
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '^' | 'N' => Ok(Direction::North),
+            '>' | 'E' => Ok(Direction::East),
+            'v' | 'S' => Ok(Direction::South),
+            '<' | 'W' => Ok(Direction::West),
+            _ => Err(ParseDirectionError(value.to_string())),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = ParseDirectionError;
+
+    /// Like parsing a single character, but also accepts diagonals such as `NE` or `SW`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "NE" => Ok(Direction::NorthEast),
+            "SE" => Ok(Direction::SouthEast),
+            "SW" => Ok(Direction::SouthWest),
+            "NW" => Ok(Direction::NorthWest),
+            _ => {
+                let mut chars = s.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(value), None) => Direction::try_from(value),
+                    _ => Err(ParseDirectionError(s.to_owned())),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use glam::IVec2;
+
+    use crate::direction::Direction;
+
+    #[test]
+    fn rotates_quarter_turns() {
+        assert_eq!(Direction::North.clockwise(), Direction::East);
+        assert_eq!(Direction::North.counter_clockwise(), Direction::West);
+        assert_eq!(Direction::SouthWest.clockwise(), Direction::NorthWest);
+    }
+
+    #[test]
+    fn rotates_eighth_turns() {
+        assert_eq!(Direction::North.clockwise_eighth(), Direction::NorthEast);
+        assert_eq!(
+            Direction::North.counter_clockwise_eighth(),
+            Direction::NorthWest
+        );
+    }
+
+    #[test]
+    fn opposites() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.opposite().vector(), -direction.vector());
+            assert_eq!(direction.opposite().opposite(), direction);
+        }
+    }
+
+    #[test]
+    fn turn_distances() {
+        assert_eq!(Direction::North.quarter_turns_to(Direction::North), 0);
+        assert_eq!(Direction::North.quarter_turns_to(Direction::East), 1);
+        assert_eq!(Direction::East.quarter_turns_to(Direction::North), 1);
+        assert_eq!(Direction::North.quarter_turns_to(Direction::South), 2);
+        assert_eq!(Direction::West.quarter_turns_to(Direction::North), 1);
+        assert_eq!(Direction::North.eighth_turns_to(Direction::NorthWest), 1);
+        assert_eq!(Direction::North.quarter_turns_to(Direction::NorthWest), 1);
+    }
+
+    #[test]
+    fn vectors_point_down_for_south() {
+        assert_eq!(IVec2::from(Direction::South), IVec2::Y);
+        assert_eq!(Direction::NorthWest.vector(), IVec2::NEG_ONE);
+    }
+
+    #[test]
+    fn parses_arrows_and_compass_directions() {
+        assert_eq!(Direction::try_from('^'), Ok(Direction::North));
+        assert_eq!(Direction::try_from('<'), Ok(Direction::West));
+        assert_eq!("E".parse(), Ok(Direction::East));
+        assert_eq!("SW".parse(), Ok(Direction::SouthWest));
+        assert!(Direction::try_from('x').is_err());
+        assert!("NNE".parse::<Direction>().is_err());
+    }
+
+    #[test]
+    fn diagonals() {
+        assert!(Direction::DIAGONAL.iter().all(|d| d.is_diagonal()));
+        assert!(!Direction::CARDINAL.iter().any(|d| d.is_diagonal()));
+    }
+}
@@ -0,0 +1,334 @@
+use std::ops::{Index, IndexMut};
+
+use glam::IVec2;
+
+use crate::Direction;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseGridError {
+    #[error("the grid does not contain any cells")]
+    Empty,
+    #[error("line {line} contains {found} cells, expected {expected} like the first line")]
+    UnevenRow {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    #[error("unexpected '{cell}' at line {line}, column {column}")]
+    InvalidCell {
+        line: usize,
+        column: usize,
+        cell: char,
+    },
+}
+
+/// A rectangular grid of cells, stored row by row. Positions are signed vectors where `x` is
+/// the column and `y` the row, so stepping outside of the grid can be checked instead of
+/// wrapping around.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from its cells in row-major order.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "A {width}x{height} grid needs {} cells",
+            width * height
+        );
+
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(IVec2) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| IVec2::new(x as i32, y as i32)))
+            .map(&mut cell)
+            .collect();
+
+        Self::new(width, height, cells)
+    }
+
+    /// Parses every line of the input as a row of the grid, mapping each character to a cell.
+    /// Characters that can't be mapped are reported by their (1-based) line and column.
+    pub fn parse(
+        input: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseGridError> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        for (line_index, line) in input.trim_end_matches(['\r', '\n']).lines().enumerate() {
+            let row_start = cells.len();
+
+            for (column_index, character) in line.chars().enumerate() {
+                cells.push(cell(character).ok_or(ParseGridError::InvalidCell {
+                    line: line_index + 1,
+                    column: column_index + 1,
+                    cell: character,
+                })?);
+            }
+
+            let row_width = cells.len() - row_start;
+            match width {
+                None => width = Some(row_width),
+                Some(expected) if expected != row_width => {
+                    return Err(ParseGridError::UnevenRow {
+                        line: line_index + 1,
+                        expected,
+                        found: row_width,
+                    })
+                }
+                Some(_) => (),
+            }
+
+            height += 1;
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Self::new(width, height, cells)),
+            _ => Err(ParseGridError::Empty),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, position: IVec2) -> bool {
+        self.index_of(position).is_some()
+    }
+
+    pub fn get(&self, position: IVec2) -> Option<&T> {
+        self.index_of(position).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, position: IVec2) -> Option<&mut T> {
+        self.index_of(position).map(|index| &mut self.cells[index])
+    }
+
+    /// All positions in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = IVec2> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| IVec2::new(x as i32, y as i32)))
+    }
+
+    /// All cells together with their position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (IVec2, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The position of the first cell (row by row) matching the predicate.
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<IVec2> {
+        self.iter()
+            .find_map(|(position, cell)| predicate(cell).then_some(position))
+    }
+
+    /// The neighbours sharing an edge with the given position that are inside the grid.
+    pub fn neighbours(&self, position: IVec2) -> impl Iterator<Item = (IVec2, &T)> {
+        self.cells_in_directions(position, &Direction::CARDINAL)
+    }
+
+    /// Like [`Grid::neighbours`], but including the diagonal neighbours.
+    pub fn all_neighbours(&self, position: IVec2) -> impl Iterator<Item = (IVec2, &T)> {
+        self.cells_in_directions(position, &Direction::ALL)
+    }
+
+    /// Walks from `start` (inclusive) in steps of `step` until leaving the grid. A step along
+    /// one axis walks a row or column, a step like `(1, 1)` walks a diagonal.
+    pub fn ray(&self, start: IVec2, step: IVec2) -> impl Iterator<Item = (IVec2, &T)> {
+        std::iter::successors(Some(start), move |position| Some(*position + step))
+            .map_while(|position| self.get(position).map(|cell| (position, cell)))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn map<U>(&self, cell: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(
+            self.width,
+            self.height,
+            self.cells.iter().map(cell).collect(),
+        )
+    }
+
+    /// Draws the grid as text, one line per row.
+    pub fn render(&self, mut cell: impl FnMut(&T) -> char) -> String {
+        let mut rendered = String::with_capacity((self.width + 1) * self.height);
+
+        for row in self.rows() {
+            rendered.extend(row.iter().map(&mut cell));
+            rendered.push('\n');
+        }
+
+        rendered
+    }
+
+    fn cells_in_directions<'a>(
+        &'a self,
+        position: IVec2,
+        directions: &'static [Direction],
+    ) -> impl Iterator<Item = (IVec2, &'a T)> {
+        directions.iter().filter_map(move |direction| {
+            let neighbour = position + direction.vector();
+            self.get(neighbour).map(|cell| (neighbour, cell))
+        })
+    }
+
+    fn index_of(&self, position: IVec2) -> Option<usize> {
+        let x = usize::try_from(position.x).ok()?;
+        let y = usize::try_from(position.y).ok()?;
+
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+}
+
+impl<T> Index<IVec2> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: IVec2) -> &Self::Output {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{position} is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<IVec2> for Grid<T> {
+    fn index_mut(&mut self, position: IVec2) -> &mut Self::Output {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{position} is outside of the grid"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use glam::IVec2;
+
+    use crate::grid::{Grid, ParseGridError};
+
+    const INPUT: &str = "abc\ndef\n";
+
+    fn grid() -> Grid<char> {
+        Grid::parse(INPUT, Some).unwrap()
+    }
+
+    #[test]
+    fn parses_non_square_grids() {
+        let grid = grid();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[IVec2::new(2, 1)], 'f');
+    }
+
+    #[test]
+    fn parses_windows_line_endings() {
+        assert_eq!(Grid::parse("abc\r\ndef", Some), Ok(grid()));
+    }
+
+    #[test]
+    fn reports_invalid_cells() {
+        let error = Grid::parse(INPUT, |c| (c != 'e').then_some(c)).unwrap_err();
+
+        assert_eq!(
+            error,
+            ParseGridError::InvalidCell {
+                line: 2,
+                column: 2,
+                cell: 'e'
+            }
+        );
+    }
+
+    #[test]
+    fn reports_uneven_rows() {
+        let error = Grid::parse("abc\nde\n", Some).unwrap_err();
+
+        assert_eq!(
+            error,
+            ParseGridError::UnevenRow {
+                line: 2,
+                expected: 3,
+                found: 2
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_empty_input() {
+        assert_eq!(Grid::parse("\n", Some), Err(ParseGridError::Empty));
+    }
+
+    #[test]
+    fn positions_outside_the_grid_are_checked() {
+        let grid = grid();
+
+        assert_eq!(grid.get(IVec2::new(-1, 0)), None);
+        assert_eq!(grid.get(IVec2::new(3, 0)), None);
+        assert_eq!(grid.get(IVec2::new(0, 2)), None);
+    }
+
+    #[test]
+    fn neighbours_stay_inside_the_grid() {
+        let grid = grid();
+
+        let neighbours: String = grid.neighbours(IVec2::ZERO).map(|(_, c)| c).collect();
+        assert_eq!(neighbours, "bd");
+
+        let all_neighbours: String = grid
+            .all_neighbours(IVec2::new(1, 0))
+            .map(|(_, c)| c)
+            .collect();
+        assert_eq!(all_neighbours, "cfeda");
+    }
+
+    #[test]
+    fn rays_walk_until_the_edge() {
+        let grid = grid();
+
+        let row: String = grid.ray(IVec2::ZERO, IVec2::X).map(|(_, c)| c).collect();
+        let diagonal: String = grid.ray(IVec2::ZERO, IVec2::ONE).map(|(_, c)| c).collect();
+
+        assert_eq!(row, "abc");
+        assert_eq!(diagonal, "ae");
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = grid();
+
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+    }
+
+    #[test]
+    fn finds_cells() {
+        assert_eq!(grid().find(|c| *c == 'e'), Some(IVec2::new(1, 1)));
+    }
+
+    #[test]
+    fn renders_back_to_text() {
+        assert_eq!(grid().render(|c| *c), INPUT);
+    }
+}
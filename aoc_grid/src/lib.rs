@@ -0,0 +1,6 @@
+pub use direction::{Direction, ParseDirectionError};
+pub use glam::IVec2;
+pub use grid::{Grid, ParseGridError};
+
+mod direction;
+mod grid;
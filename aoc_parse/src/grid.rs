@@ -0,0 +1,152 @@
+use aoc_grid::Grid;
+use winnow::{
+    combinator::fail,
+    error::{ContextError, StrContext, StrContextValue},
+    token::take_till,
+    Parser, Result,
+};
+
+use crate::lines::next_line;
+
+/// A grid of characters, mapping each of them to a cell. The grid ends at a blank line or the
+/// end of the input, leaving the line ending after its last row alone. Characters that can't
+/// be mapped and rows that are not as wide as the first are errors.
+pub fn grid<'i, T>(
+    cell: impl FnMut(char) -> Option<T>,
+) -> impl Parser<&'i str, Grid<T>, ContextError> {
+    grid_of(&[], cell)
+}
+
+/// A [`grid`] where a character that can't be mapped is reported along with the `expected`
+/// characters (or descriptions of them, such as "a digit").
+pub fn grid_of<'i, T>(
+    expected: &'static [StrContextValue],
+    mut cell: impl FnMut(char) -> Option<T>,
+) -> impl Parser<&'i str, Grid<T>, ContextError> {
+    move |input: &mut &'i str| {
+        let mut cells = vec![];
+        let width = row(input, &mut cell, expected, &mut cells)?;
+        let mut height = 1;
+
+        while next_line(input) {
+            let row_start = *input;
+            if row(input, &mut cell, expected, &mut cells)? != width {
+                *input = row_start;
+                return fail
+                    .context(StrContext::Label("row width"))
+                    .context(StrContext::Expected(StrContextValue::Description(
+                        "as many cells as the first row",
+                    )))
+                    .parse_next(input);
+            }
+            height += 1;
+        }
+
+        Ok(Grid::new(width, height, cells))
+    }
+}
+
+/// Adds the cells of a single row, returning how many there are.
+fn row<T>(
+    input: &mut &str,
+    cell: &mut impl FnMut(char) -> Option<T>,
+    expected: &[StrContextValue],
+    cells: &mut Vec<T>,
+) -> Result<usize> {
+    let row_start = *input;
+    let line = take_till(1.., ['\r', '\n'])
+        .context(StrContext::Expected(StrContextValue::Description(
+            "a row of the grid",
+        )))
+        .parse_next(input)?;
+
+    for (offset, character) in line.char_indices() {
+        match cell(character) {
+            Some(cell) => cells.push(cell),
+            None => {
+                *input = &row_start[offset..];
+                let mut error = ContextError::new();
+                error.push(StrContext::Label("grid cell"));
+                for expected in expected {
+                    error.push(StrContext::Expected(expected.clone()));
+                }
+                return Err(error);
+            }
+        }
+    }
+
+    Ok(line.chars().count())
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_grid::IVec2;
+    use winnow::Parser;
+
+    use winnow::error::StrContextValue;
+
+    use crate::{
+        grid::{grid, grid_of},
+        lines::parse_all,
+    };
+
+    fn digit(character: char) -> Option<u32> {
+        character.to_digit(10)
+    }
+
+    #[test]
+    fn maps_every_cell() {
+        let grid = grid(digit).parse("123\n456").unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[IVec2::new(2, 1)], 6);
+    }
+
+    #[test]
+    fn ends_at_a_blank_line() {
+        let mut input = "12\r\n34\r\n\r\nrest";
+
+        let grid = grid(digit).parse_next(&mut input).unwrap();
+
+        assert_eq!(grid.height(), 2);
+        assert_eq!(input, "\r\n\r\nrest");
+    }
+
+    #[test]
+    fn invalid_cell_is_located() {
+        let error = parse_all(grid(digit), "12\n3x\n").unwrap_err();
+
+        assert_eq!((error.location.line, error.location.column), (2, 2));
+        assert_eq!(error.message, "invalid grid cell");
+        assert!(error.expected.is_empty());
+    }
+
+    #[test]
+    fn invalid_cell_lists_the_expected_characters() {
+        const EXPECTED: &[StrContextValue] = &[
+            StrContextValue::CharLiteral('.'),
+            StrContextValue::Description("a digit"),
+        ];
+        let cell = |character: char| (character == '.').then_some(0).or(digit(character));
+
+        let error = parse_all(
+            grid_of(EXPECTED, cell),
+            "1.
+3x
+",
+        )
+        .unwrap_err();
+
+        assert_eq!((error.location.line, error.location.column), (2, 2));
+        assert_eq!(error.expected, ["`.`", "a digit"]);
+    }
+
+    #[test]
+    fn rows_must_be_equally_wide() {
+        let error = parse_all(grid(digit), "12\n345\n").unwrap_err();
+
+        assert_eq!(error.location.line, 2);
+        assert_eq!(error.message, "invalid row width");
+        assert_eq!(error.expected, ["as many cells as the first row"]);
+    }
+}
@@ -0,0 +1,10 @@
+pub use grid::{grid, grid_of};
+pub use lines::{blank_line, line_end, lines, parse_all, sections, syntax_error};
+pub use numbers::{
+    list, next_signed, next_unsigned, signed, signed_list, signed_pair, unsigned, unsigned_list,
+    unsigned_pair,
+};
+
+mod grid;
+mod lines;
+mod numbers;
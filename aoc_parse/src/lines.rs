@@ -0,0 +1,161 @@
+use aoc_common::error::SyntaxError;
+use winnow::{
+    ascii::{line_ending, multispace0},
+    combinator::{alt, eof, separated, terminated},
+    error::{ContextError, ParseError, StrContext},
+    Parser, Result,
+};
+
+/// The end of a line: `\n`, `\r\n`, or the end of the input when the last line has no newline.
+pub fn line_end<'i>(input: &mut &'i str) -> Result<&'i str> {
+    alt((line_ending, eof)).parse_next(input)
+}
+
+/// The empty line between two sections, together with the line ending before it.
+pub fn blank_line<'i>(input: &mut &'i str) -> Result<&'i str> {
+    (line_ending, line_ending).take().parse_next(input)
+}
+
+/// At least one item, each on its own line. The lines end at a blank line or the end of the
+/// input, leaving the line ending after the last item alone so another section can follow. Any
+/// other line has to be an item, so an error points at the line that is wrong.
+pub fn lines<'i, O>(
+    mut item: impl Parser<&'i str, O, ContextError>,
+) -> impl Parser<&'i str, Vec<O>, ContextError> {
+    move |input: &mut &'i str| {
+        let mut items = vec![item.parse_next(input)?];
+
+        while next_line(input) {
+            items.push(item.parse_next(input)?);
+        }
+
+        Ok(items)
+    }
+}
+
+/// Moves to the start of the next line when it continues the current block of lines, leaving
+/// the input alone at a blank line, or when only whitespace is left.
+pub(crate) fn next_line(input: &mut &str) -> bool {
+    let before_line_ending = *input;
+    let is_next_line = line_ending::<_, ContextError>.parse_next(input).is_ok()
+        && !input.trim_start().is_empty()
+        && !input.starts_with(['\r', '\n']);
+
+    if !is_next_line {
+        *input = before_line_ending;
+    }
+    is_next_line
+}
+
+/// At least one section, with a blank line between every two of them, such as the claw
+/// machines of day thirteen.
+pub fn sections<'i, O>(
+    section: impl Parser<&'i str, O, ContextError>,
+) -> impl Parser<&'i str, Vec<O>, ContextError> {
+    separated(1.., section, blank_line)
+}
+
+/// Parses a whole puzzle input, reporting where it does not match. Whitespace after the parsed
+/// input is allowed, so it does not matter whether the last line ends with a newline.
+pub fn parse_all<'i, O>(
+    parser: impl Parser<&'i str, O, ContextError>,
+    input: &'i str,
+) -> std::result::Result<O, SyntaxError> {
+    terminated(parser, multispace0)
+        .parse(input)
+        .map_err(|error| syntax_error(input, &error))
+}
+
+/// Converts the error of a winnow parser, using the label of the context as message and the
+/// expected values as alternatives.
+pub fn syntax_error(input: &str, error: &ParseError<&str, ContextError>) -> SyntaxError {
+    let context = error.inner();
+    let label = context.context().find_map(|context| match context {
+        StrContext::Label(label) => Some(label),
+        _ => None,
+    });
+
+    let message = match (label, context.cause()) {
+        (Some(label), Some(cause)) => format!("invalid {label}, {cause}"),
+        (Some(label), None) => format!("invalid {label}"),
+        (None, Some(cause)) => cause.to_string(),
+        (None, None) => String::new(),
+    };
+
+    let expected = context.context().filter_map(|context| match context {
+        StrContext::Expected(expected) => Some(expected),
+        _ => None,
+    });
+
+    SyntaxError::new(input, error.offset(), message).with_expected(expected)
+}
+
+#[cfg(test)]
+mod test {
+    use winnow::{ascii::alpha1, Parser};
+
+    use crate::{
+        grid::grid,
+        lines::{line_end, lines, parse_all, sections},
+        numbers::{unsigned, unsigned_list},
+    };
+
+    #[test]
+    fn line_end_accepts_the_end_of_the_input() {
+        let mut input = "a\r\nb";
+
+        assert_eq!((alpha1, line_end).parse_next(&mut input), Ok(("a", "\r\n")));
+        assert_eq!((alpha1, line_end).parse_next(&mut input), Ok(("b", "")));
+    }
+
+    #[test]
+    fn trailing_newline_is_optional() {
+        for input in ["1\n2\n3", "1\n2\n3\n", "1\r\n2\r\n3\r\n", "1\n2\n3\n\n"] {
+            assert_eq!(
+                parse_all(lines(unsigned::<u8>()), input),
+                Ok(vec![1, 2, 3]),
+                "{input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn sections_of_lines() {
+        let input = "1,2\n3\n\n4\r\n\r\n5,6\n";
+
+        let parsed = parse_all(sections(lines(unsigned_list::<u8, _>(','))), input);
+
+        assert_eq!(
+            parsed,
+            Ok(vec![
+                vec![vec![1, 2], vec![3]],
+                vec![vec![4]],
+                vec![vec![5, 6]]
+            ])
+        );
+    }
+
+    #[test]
+    fn errors_are_located() {
+        let error = parse_all(lines(unsigned::<u8>()), "1\n2\nx\n").unwrap_err();
+
+        assert_eq!((error.location.line, error.location.column), (3, 1));
+        assert_eq!(error.expected, ["a number without sign"]);
+    }
+
+    #[test]
+    fn errors_keep_label_and_alternatives() {
+        let error = parse_all(
+            grid(|c| c.to_digit(10)),
+            "12
+3x
+",
+        )
+        .unwrap_err();
+        assert_eq!(error.message, "invalid grid cell");
+
+        let error = parse_all(unsigned_list::<u8, _>(','), "x").unwrap_err();
+        assert_eq!(error.message, "unexpected input");
+        assert_eq!(error.expected, ["a number without sign"]);
+    }
+}
@@ -0,0 +1,148 @@
+use winnow::{
+    ascii::{dec_int, dec_uint, Int, Uint},
+    combinator::{preceded, separated_pair},
+    error::{ContextError, StrContext, StrContextValue},
+    token::take_till,
+    Parser,
+};
+
+/// A decimal number with an optional sign, such as `-12` or `+3`.
+pub fn signed<'i, T: Int>() -> impl Parser<&'i str, T, ContextError> {
+    dec_int.context(StrContext::Expected(StrContextValue::Description(
+        "a number",
+    )))
+}
+
+/// A decimal number without a sign, such as `12`.
+pub fn unsigned<'i, T: Uint>() -> impl Parser<&'i str, T, ContextError> {
+    dec_uint.context(StrContext::Expected(StrContextValue::Description(
+        "a number without sign",
+    )))
+}
+
+/// Two signed numbers with a separator in between, such as `3,-4` for `signed_pair(',')`. Map
+/// the pair with `IVec2::from` (or any other vector of glam) to get a vector.
+pub fn signed_pair<'i, T: Int, S>(
+    separator: impl Parser<&'i str, S, ContextError>,
+) -> impl Parser<&'i str, (T, T), ContextError> {
+    separated_pair(signed(), separator, signed())
+}
+
+/// Like [`signed_pair`], but without signs.
+pub fn unsigned_pair<'i, T: Uint, S>(
+    separator: impl Parser<&'i str, S, ContextError>,
+) -> impl Parser<&'i str, (T, T), ContextError> {
+    separated_pair(unsigned(), separator, unsigned())
+}
+
+/// At least one signed number, with a separator between every two of them.
+pub fn signed_list<'i, T: Int, S>(
+    separator: impl Parser<&'i str, S, ContextError>,
+) -> impl Parser<&'i str, Vec<T>, ContextError> {
+    list(signed(), separator)
+}
+
+/// At least one number without sign, with a separator between every two of them, such as the
+/// program `0,1,5,4,3,0` for `unsigned_list(',')`.
+pub fn unsigned_list<'i, T: Uint, S>(
+    separator: impl Parser<&'i str, S, ContextError>,
+) -> impl Parser<&'i str, Vec<T>, ContextError> {
+    list(unsigned(), separator)
+}
+
+/// At least one item, with a separator between every two of them. Unlike `separated` of
+/// winnow an item has to follow every separator, so an error points at the item that is wrong
+/// instead of at the separator before it.
+pub fn list<'i, O, S>(
+    mut item: impl Parser<&'i str, O, ContextError>,
+    mut separator: impl Parser<&'i str, S, ContextError>,
+) -> impl Parser<&'i str, Vec<O>, ContextError> {
+    move |input: &mut &'i str| {
+        let mut items = vec![item.parse_next(input)?];
+
+        loop {
+            let before_separator = *input;
+            if separator.parse_next(input).is_err() {
+                *input = before_separator;
+                break;
+            }
+
+            let next = item.parse_next(input)?;
+            if input.len() == before_separator.len() {
+                // Neither consumed anything, stop instead of finding empty items forever
+                *input = before_separator;
+                break;
+            }
+            items.push(next);
+        }
+
+        Ok(items)
+    }
+}
+
+/// The next signed number, skipping any text before it. Useful for numbers in sentences, such
+/// as `Button A: X+94, Y-34`. A minus is always taken as the sign of the number after it.
+pub fn next_signed<'i, T: Int>() -> impl Parser<&'i str, T, ContextError> {
+    preceded(
+        take_till(0.., |c: char| c.is_ascii_digit() || c == '-'),
+        signed(),
+    )
+}
+
+/// The next number without sign, skipping any text before it, signs included.
+pub fn next_unsigned<'i, T: Uint>() -> impl Parser<&'i str, T, ContextError> {
+    preceded(take_till(0.., |c: char| c.is_ascii_digit()), unsigned())
+}
+
+#[cfg(test)]
+mod test {
+    use glam::IVec2;
+    use winnow::Parser;
+
+    use crate::numbers::{
+        next_signed, next_unsigned, signed_list, signed_pair, unsigned, unsigned_list,
+        unsigned_pair,
+    };
+
+    #[test]
+    fn signed_pair_as_a_vector() {
+        let vector = signed_pair(',').map(IVec2::from).parse("-3,+12");
+
+        assert_eq!(vector, Ok(IVec2::new(-3, 12)));
+    }
+
+    #[test]
+    fn unsigned_pair_rejects_signs() {
+        assert_eq!(unsigned_pair::<u8, _>(" x ").parse("3 x 4"), Ok((3, 4)));
+        assert!(unsigned_pair::<u8, _>(',').parse("3,-4").is_err());
+    }
+
+    #[test]
+    fn unsigned_overflow_is_an_error() {
+        assert_eq!(unsigned::<u8>().parse("255"), Ok(255));
+        assert!(unsigned::<u8>().parse("256").is_err());
+    }
+
+    #[test]
+    fn lists_need_a_number_after_every_separator() {
+        assert_eq!(
+            unsigned_list::<u8, _>(',').parse("0,1,5,4"),
+            Ok(vec![0, 1, 5, 4])
+        );
+        assert_eq!(signed_list::<i32, _>(' ').parse("7 -2"), Ok(vec![7, -2]));
+
+        let error = unsigned_list::<u8, _>(',').parse("0,1,").unwrap_err();
+        assert_eq!(error.offset(), 4);
+        assert_eq!(error.inner().to_string(), "expected a number without sign");
+    }
+
+    #[test]
+    fn next_numbers_skip_text() {
+        let mut input = "Button A: X+94, Y-34\nPrize: X=8400";
+
+        assert_eq!(next_signed::<i64>().parse_next(&mut input), Ok(94));
+        assert_eq!(next_signed::<i64>().parse_next(&mut input), Ok(-34));
+        assert_eq!(next_unsigned::<u64>().parse_next(&mut input), Ok(8400));
+        assert!(next_unsigned::<u64>().parse_next(&mut input).is_err());
+    }
+}
@@ -0,0 +1,3 @@
+pub use search::{astar, bfs, dijkstra, Search};
+
+mod search;
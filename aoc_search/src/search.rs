@@ -0,0 +1,394 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+use num_traits::Zero;
+
+/// The outcome of a search: the lowest cost to every state that was reached, together with all
+/// states it can be reached from at that cost.
+///
+/// A search stops once every goal with the lowest cost has been reached, so states that cost
+/// more than the goal may be missing. Without a goal every reachable state is visited.
+#[derive(Debug, Clone)]
+pub struct Search<S, C> {
+    distances: HashMap<S, C>,
+    predecessors: HashMap<S, Vec<S>>,
+    goals: Vec<S>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Search<S, C> {
+    fn new() -> Self {
+        Self {
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+            goals: vec![],
+        }
+    }
+
+    /// The lowest cost to reach a state, `None` when it was not reached.
+    pub fn distance(&self, state: &S) -> Option<C> {
+        self.distances.get(state).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<S, C> {
+        &self.distances
+    }
+
+    /// Every state that leads to the given state on a path of the lowest cost. Start states and
+    /// states that were not reached have no predecessors.
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map_or(&[], Vec::as_slice)
+    }
+
+    /// All goals that were reached at the lowest cost, in the order they were found.
+    pub fn goals(&self) -> &[S] {
+        &self.goals
+    }
+
+    /// The lowest cost to reach a goal, `None` when no goal was reached.
+    pub fn goal_distance(&self) -> Option<C> {
+        self.goals.first().and_then(|goal| self.distance(goal))
+    }
+
+    /// One of the paths of the lowest cost from a start to the given state, including both.
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        self.distances.get(state)?;
+
+        let mut path = vec![state.clone()];
+        while let Some(previous) = self.predecessors(path.last()?).first() {
+            path.push(previous.clone());
+        }
+
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every state on any path of the lowest cost from a start to one of the targets.
+    pub fn states_on_paths_to<'a>(&self, targets: impl IntoIterator<Item = &'a S>) -> HashSet<S>
+    where
+        S: 'a,
+    {
+        let mut on_path = HashSet::new();
+        let mut to_check: Vec<_> = targets
+            .into_iter()
+            .filter(|target| self.distances.contains_key(target))
+            .cloned()
+            .collect();
+
+        while let Some(state) = to_check.pop() {
+            if on_path.insert(state.clone()) {
+                to_check.extend(self.predecessors(&state).iter().cloned());
+            }
+        }
+
+        on_path
+    }
+
+    /// How many different paths of the lowest cost lead from a start to the given state. Steps
+    /// are assumed to have a positive cost, otherwise there could be endless paths.
+    pub fn count_paths_to(&self, state: &S) -> usize {
+        if !self.distances.contains_key(state) {
+            return 0;
+        }
+
+        // Depth first, counting a state once all of its predecessors have been counted
+        let mut counts: HashMap<S, usize> = HashMap::new();
+        let mut to_count = vec![(state.clone(), false)];
+
+        while let Some((state, predecessors_counted)) = to_count.pop() {
+            if counts.contains_key(&state) {
+                continue;
+            }
+
+            let predecessors = self.predecessors(&state);
+            if predecessors.is_empty() {
+                counts.insert(state, 1);
+            } else if predecessors_counted {
+                let count = predecessors
+                    .iter()
+                    .map(|predecessor| counts.get(predecessor).copied().unwrap_or(0))
+                    .sum();
+                counts.insert(state, count);
+            } else {
+                to_count.push((state.clone(), true));
+                to_count.extend(
+                    predecessors
+                        .iter()
+                        .filter(|predecessor| !counts.contains_key(predecessor))
+                        .map(|predecessor| (predecessor.clone(), false)),
+                );
+            }
+        }
+
+        counts[state]
+    }
+
+    /// Records reaching `next` from `state`, returning whether this is a new lowest cost.
+    fn reach(&mut self, state: &S, next: S, cost: C) -> bool
+    where
+        C: Ord,
+    {
+        match self.distances.get(&next) {
+            Some(known) if cost > *known => false,
+            Some(known) if cost == *known => {
+                self.predecessors
+                    .entry(next)
+                    .or_default()
+                    .push(state.clone());
+                false
+            }
+            _ => {
+                self.distances.insert(next.clone(), cost);
+                self.predecessors.insert(next, vec![state.clone()]);
+                true
+            }
+        }
+    }
+}
+
+/// Breadth first search where every step costs one, stopping once the nearest goals have been
+/// reached. Use `|_| false` as goal to visit every reachable state.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        search.distances.insert(start.clone(), 0);
+        queue.push_back(start);
+    }
+
+    while let Some(state) = queue.pop_front() {
+        let distance = search.distances[&state];
+
+        if search.goal_distance().is_some_and(|goal| distance > goal) {
+            break;
+        }
+
+        if is_goal(&state) {
+            search.goals.push(state);
+            continue;
+        }
+
+        for next in successors(&state) {
+            if search.reach(&state, next.clone(), distance + 1) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    search
+}
+
+/// Finds the paths of the lowest cost, where the successors of a state come with the cost of
+/// the step to them. Costs can not be negative.
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Zero + Ord + Copy,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, successors, |_| C::zero(), is_goal)
+}
+
+/// Like [`dijkstra`], but searches towards the goal first using a heuristic that estimates the
+/// remaining cost. The heuristic has to be consistent (never estimate more than the cost of a
+/// step plus the estimate after it), otherwise the costs and predecessors can be wrong.
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Zero + Ord + Copy,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut search = Search::new();
+    let mut frontier = BinaryHeap::new();
+
+    for start in starts {
+        search.distances.insert(start.clone(), C::zero());
+        frontier.push(Frontier {
+            estimate: heuristic(&start),
+            cost: C::zero(),
+            state: start,
+        });
+    }
+
+    while let Some(Frontier {
+        estimate,
+        cost,
+        state,
+    }) = frontier.pop()
+    {
+        if search.goal_distance().is_some_and(|goal| estimate > goal) {
+            break;
+        }
+
+        // Skip if we already found a cheaper path to this state
+        if search.distances[&state] < cost {
+            continue;
+        }
+
+        if is_goal(&state) {
+            search.goals.push(state);
+            continue;
+        }
+
+        for (next, step_cost) in successors(&state) {
+            let next_cost = cost + step_cost;
+            if search.reach(&state, next.clone(), next_cost) {
+                frontier.push(Frontier {
+                    estimate: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    state: next,
+                });
+            }
+        }
+    }
+
+    search
+}
+
+/// A state waiting to be visited, ordered so the lowest estimate is popped from the heap first.
+struct Frontier<S, C> {
+    estimate: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> Ord for Frontier<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Prefer the state that got furthest when the estimates are the same
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+impl<S, C: Ord> PartialOrd for Frontier<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> PartialEq for Frontier<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<S, C: Ord> Eq for Frontier<S, C> {}
+
+#[cfg(test)]
+mod test {
+    use crate::search::{astar, bfs, dijkstra};
+
+    /// A diamond where both ways from 0 to 3 cost the same, and a detour through 4 and 5.
+    fn diamond(state: &u32) -> Vec<(u32, u32)> {
+        match state {
+            0 => vec![(1, 1), (2, 1), (4, 1)],
+            1 | 2 => vec![(3, 2)],
+            4 => vec![(5, 1)],
+            5 => vec![(3, 5)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn bfs_counts_steps() {
+        let search = bfs([0], |n: &i32| [n + 1, n * 2], |n| *n == 10);
+
+        // 0, 1, 2, 4, 5, 10
+        assert_eq!(search.goal_distance(), Some(5));
+        assert_eq!(search.goals(), [10]);
+    }
+
+    #[test]
+    fn bfs_visits_everything_without_a_goal() {
+        let search = bfs([0], |n: &u32| (*n < 4).then_some(n + 1), |_| false);
+
+        assert_eq!(search.distances().len(), 5);
+        assert_eq!(search.distance(&4), Some(4));
+        assert_eq!(search.goal_distance(), None);
+    }
+
+    #[test]
+    fn dijkstra_keeps_every_optimal_predecessor() {
+        let search = dijkstra([0], diamond, |state| *state == 3);
+
+        let mut predecessors = search.predecessors(&3).to_vec();
+        predecessors.sort();
+
+        assert_eq!(search.goal_distance(), Some(3));
+        assert_eq!(predecessors, [1, 2]);
+        assert_eq!(search.count_paths_to(&3), 2);
+    }
+
+    #[test]
+    fn states_on_optimal_paths_skip_detours() {
+        let search = dijkstra([0], diamond, |state| *state == 3);
+
+        let mut on_path: Vec<_> = search
+            .states_on_paths_to(search.goals())
+            .into_iter()
+            .collect();
+        on_path.sort();
+
+        assert_eq!(on_path, [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn reconstructs_a_path() {
+        let search = dijkstra([0], diamond, |_| false);
+
+        assert_eq!(search.path_to(&5), Some(vec![0, 4, 5]));
+        assert_eq!(search.path_to(&0), Some(vec![0]));
+        assert_eq!(search.path_to(&9), None);
+    }
+
+    #[test]
+    fn unreachable_goal() {
+        let search = dijkstra([0], diamond, |state| *state == 9);
+
+        assert_eq!(search.goal_distance(), None);
+        assert_eq!(search.count_paths_to(&9), 0);
+    }
+
+    #[test]
+    fn astar_on_a_line_agrees_with_dijkstra() {
+        let successors = |n: &i32| [(n - 1, 1), (n + 1, 1)];
+        let heuristic = |n: &i32| (7 - n).unsigned_abs() as i32;
+
+        let search = astar([0], successors, heuristic, |n| *n == 7);
+
+        assert_eq!(search.goal_distance(), Some(7));
+        assert_eq!(search.path_to(&7).unwrap().len(), 8);
+        // The heuristic keeps the search from going the wrong way
+        assert_eq!(search.distance(&-2), None);
+    }
+
+    #[test]
+    fn multiple_starts() {
+        let search = bfs([0, 20], |n: &i32| [n + 1, n - 1], |n| *n == 17);
+
+        assert_eq!(search.goal_distance(), Some(3));
+        assert_eq!(search.path_to(&17).unwrap().first(), Some(&20));
+    }
+}
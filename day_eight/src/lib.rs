@@ -0,0 +1,169 @@
+use std::collections::HashMap;
+
+use aoc_common::{
+    error::SyntaxError,
+    input::InputFiles,
+    random::Rng,
+    solution::{Day, Generate, Solution},
+};
+use aoc_grid::{Grid, IVec2};
+use aoc_parse::{grid_of, parse_all};
+use itertools::Itertools;
+use winnow::error::StrContextValue;
+
+pub const INPUT_FILES: InputFiles = InputFiles::new(env!("CARGO_MANIFEST_DIR"), "input_small.txt");
+
+pub const DAY: Day = Day::new::<DayEight>(8, &INPUT_FILES).with_generator::<DayEight>();
+
+pub struct DayEight;
+
+impl Solution for DayEight {
+    type Input = Roof;
+    type PartOne = usize;
+    type PartTwo = usize;
+    type Error = SyntaxError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne, Self::Error> {
+        Ok(part_1(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo, Self::Error> {
+        Ok(part_2(input))
+    }
+}
+
+/// A roof of `size` by `size` tiles with antennas of up to six frequencies on about one in
+/// twenty tiles.
+impl Generate for DayEight {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        const FREQUENCIES: &[u8] = b"abcxyzABCXYZ0123456789";
+
+        let frequencies: Vec<char> = (0..rng.range(1..7))
+            .map(|_| *rng.pick(FREQUENCIES) as char)
+            .collect();
+
+        (0..size)
+            .map(|_| {
+                let mut row: String = (0..size)
+                    .map(|_| match rng.chance(0.05) {
+                        true => *rng.pick(&frequencies),
+                        false => '.',
+                    })
+                    .collect();
+                row.push('\n');
+                row
+            })
+            .collect()
+    }
+}
+
+pub struct Roof {
+    tiles: Grid<RoofTile>,
+}
+
+impl Roof {
+    /// All antennas on the roof, grouped by their frequency
+    fn antennas_by_frequency(&self) -> HashMap<char, Vec<IVec2>> {
+        self.tiles
+            .iter()
+            .filter_map(|(location, tile)| match tile {
+                RoofTile::Empty => None,
+                RoofTile::Antenna(antenna_id) => Some((*antenna_id, location)),
+            })
+            .into_group_map()
+    }
+}
+
+#[derive(Debug, Clone)]
+enum RoofTile {
+    Empty,
+    Antenna(char),
+}
+
+pub fn part_1(roof: &Roof) -> usize {
+    let map_of_antennas = roof.antennas_by_frequency();
+
+    let antinode_count = map_of_antennas
+        .values()
+        .flat_map(|coords| {
+            coords.iter().combinations(2).flat_map(|pair| {
+                let (coord1, coord2) = (pair[0], pair[1]);
+
+                let diff = coord1 - coord2;
+                let antinode_a = coord1 + diff;
+                let antinode_b = coord2 - diff;
+                [antinode_a, antinode_b]
+            })
+        })
+        .filter(|vec| roof.tiles.contains(*vec))
+        .unique()
+        .count();
+
+    antinode_count
+}
+
+pub fn part_2(roof: &Roof) -> usize {
+    let map_of_antennas = roof.antennas_by_frequency();
+
+    let antinode_count = map_of_antennas
+        .values()
+        .flat_map(|coords| {
+            coords.iter().combinations(2).flat_map(|pair| {
+                let (antenna, other_antenna) = (*pair[0], *pair[1]);
+
+                // Step to every tile in line, also those between the antennas when the
+                // difference has a common divisor (like 6, -2)
+                let diff = antenna - other_antenna;
+                let diff = diff / greatest_common_divisor(diff.x, diff.y);
+
+                // Now the antennas themselves also contain antinodes
+                let mut antinodes = vec![antenna];
+
+                // Walk away from the other antenna, then through it to the other edge
+                for step in [diff, -diff] {
+                    let mut coord = antenna + step;
+
+                    while roof.tiles.contains(coord) {
+                        antinodes.push(coord);
+                        coord += step;
+                    }
+                }
+
+                antinodes
+            })
+        })
+        .unique()
+        .count();
+
+    antinode_count
+}
+
+fn greatest_common_divisor(a: i32, b: i32) -> i32 {
+    if b == 0 {
+        a.abs()
+    } else {
+        greatest_common_divisor(b, a % b)
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Roof, SyntaxError> {
+    const TILES: &[StrContextValue] = &[
+        StrContextValue::CharLiteral('.'),
+        StrContextValue::Description("an antenna (a letter or digit)"),
+    ];
+
+    let tiles = parse_all(
+        grid_of(TILES, |character| match character {
+            '.' => Some(RoofTile::Empty),
+            'a'..='z' | 'A'..='Z' | '0'..='9' => Some(RoofTile::Antenna(character)),
+            _ => None,
+        }),
+        input,
+    )?;
+
+    Ok(Roof { tiles })
+}
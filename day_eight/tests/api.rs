@@ -0,0 +1,9 @@
+use day_eight::{parse_input, part_2};
+
+#[test]
+fn antinodes_between_antennas_count_when_in_line() {
+    // The antennas are 2, 4 apart, so the tile halfway at 1, 2 is in line with both
+    let roof = parse_input("a....\n.....\n.....\n.....\n..a..\n").unwrap();
+
+    assert_eq!(part_2(&roof), 3);
+}
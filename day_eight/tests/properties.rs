@@ -0,0 +1,59 @@
+use aoc_common::{random::Rng, solution::Generate};
+use day_eight::{parse_input, part_1, part_2, DayEight};
+
+const CASES: u64 = 40;
+
+type Antenna = (u8, i32, i32);
+
+fn antennas(input: &str) -> Vec<Antenna> {
+    input
+        .lines()
+        .enumerate()
+        .flat_map(|(y, row)| {
+            row.bytes()
+                .enumerate()
+                .filter(|(_, tile)| *tile != b'.')
+                .map(move |(x, frequency)| (frequency, x as i32, y as i32))
+        })
+        .collect()
+}
+
+/// Checks every tile against every pair of antennas of the same frequency.
+fn reference(antennas: &[Antenna], size: i32) -> (usize, usize) {
+    let pairs: Vec<_> = antennas
+        .iter()
+        .flat_map(|a| antennas.iter().map(move |b| (a, b)))
+        .filter(|(a, b)| a.0 == b.0 && a != b)
+        .collect();
+    let (mut antinodes, mut resonant_antinodes) = (0, 0);
+
+    for (x, y) in (0..size).flat_map(|y| (0..size).map(move |x| (x, y))) {
+        // Twice as far from `a` as from `b`, in the same direction
+        antinodes += pairs
+            .iter()
+            .any(|(a, b)| x - a.1 == 2 * (x - b.1) && y - a.2 == 2 * (y - b.2))
+            as usize;
+        // On the line through `a` and `b`
+        resonant_antinodes += pairs
+            .iter()
+            .any(|(a, b)| (b.1 - a.1) * (y - a.2) == (b.2 - a.2) * (x - a.1))
+            as usize;
+    }
+
+    (antinodes, resonant_antinodes)
+}
+
+#[test]
+fn solutions_match_the_reference() {
+    for seed in 0..CASES {
+        let size = 1 + seed as usize;
+        let input = DayEight::generate(&mut Rng::new(seed), size);
+        let roof = parse_input(&input).unwrap();
+
+        assert_eq!(
+            (part_1(&roof), part_2(&roof)),
+            reference(&antennas(&input), size as i32),
+            "seed {seed}\n{input}"
+        );
+    }
+}
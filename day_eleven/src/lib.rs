@@ -0,0 +1,152 @@
+use aoc_common::{
+    error::SyntaxError,
+    input::InputFiles,
+    random::Rng,
+    solution::{Day, Generate, Solution},
+};
+use aoc_parse::{parse_all, unsigned_list};
+use itertools::Itertools;
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+pub const INPUT_FILES: InputFiles = InputFiles::new(env!("CARGO_MANIFEST_DIR"), "input_small.txt");
+
+pub const DAY: Day = Day::new::<DayEleven>(11, &INPUT_FILES).with_generator::<DayEleven>();
+
+pub struct DayEleven;
+
+impl Solution for DayEleven {
+    type Input = Vec<u64>;
+    type PartOne = u64;
+    type PartTwo = u64;
+    type Error = SyntaxError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne, Self::Error> {
+        Ok(part_1(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo, Self::Error> {
+        Ok(part_2(input))
+    }
+}
+
+/// A line of `size` stones with numbers of up to seven digits, some of them zero.
+impl Generate for DayEleven {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let stones: Vec<String> = (0..size)
+            .map(|_| {
+                let digits = rng.range(1..8) as u32;
+                match rng.chance(0.1) {
+                    true => 0,
+                    false => rng.range(1..10_i64.pow(digits)),
+                }
+            })
+            .map(|stone| stone.to_string())
+            .collect();
+
+        stones.join(" ") + "\n"
+    }
+}
+
+pub fn part_1(stones: &[u64]) -> u64 {
+    let times_blinking = 25;
+    let amount_of_stones: u64 = stones
+        .par_iter()
+        .map(|stone| count_stones_recursively(*stone, times_blinking))
+        .sum();
+
+    amount_of_stones
+}
+
+fn count_stones_recursively(stone: u64, remaining: usize) -> u64 {
+    if remaining == 0 {
+        1
+    } else {
+        let next_remaining = remaining - 1;
+        if stone == 0 {
+            count_stones_recursively(1, next_remaining)
+        } else if check_stone_has_even_number_of_digits(stone) {
+            let (left_stone, right_stone) = split_stone_into_halves(stone);
+            count_stones_recursively(left_stone, next_remaining)
+                + count_stones_recursively(right_stone, next_remaining)
+        } else {
+            count_stones_recursively(stone * 2024, next_remaining)
+        }
+    }
+}
+
+pub fn part_2(stones: &[u64]) -> u64 {
+    // With this approach, I noticed lots of values will be repeated, but I should have
+    // used an approach that would reuse even more/cache more stones. The stone 'numbers' are very limited.
+    // I could have precaculated every stone. This would have made the calculations a lot faster!
+    //
+    //
+    // Chosen approach is a lot faster compared to brute-forcing, though. I didn't fail completely.
+    // Solution now takes 2 minutes (on slow machine)
+    // brute-force didn't finish, even after waiting for a few hours!
+    let blinks_per_chunk: usize = 25;
+    let depth = 3; // 3 * 25 = 75 blinks total
+
+    find_stones_chunked(stones, depth, blinks_per_chunk)
+}
+
+fn find_stones_chunked(stones: &[u64], remaining_repeats: usize, blinks_per_chunk: usize) -> u64 {
+    let amount_of_stones = stones.iter().copied().counts();
+
+    if remaining_repeats == 0 {
+        amount_of_stones.values().map(|i| *i as u64).sum()
+    } else {
+        amount_of_stones
+            .par_iter()
+            .fold(
+                || 0,
+                |acc, (stone, multiplier)| {
+                    acc + find_stones_chunked(
+                        &find_stones_recursively(*stone, blinks_per_chunk),
+                        remaining_repeats - 1,
+                        blinks_per_chunk,
+                    ) * *multiplier as u64
+                },
+            )
+            .sum()
+    }
+}
+
+fn find_stones_recursively(stone: u64, remaining_blinks: usize) -> Vec<u64> {
+    if remaining_blinks == 0 {
+        vec![stone]
+    } else {
+        let next_remaining = remaining_blinks - 1;
+
+        if stone == 0 {
+            find_stones_recursively(1, next_remaining)
+        } else if check_stone_has_even_number_of_digits(stone) {
+            let (left_stone, right_stone) = split_stone_into_halves(stone);
+
+            let mut stones = find_stones_recursively(left_stone, next_remaining);
+            stones.extend(find_stones_recursively(right_stone, next_remaining));
+            stones
+        } else {
+            find_stones_recursively(stone * 2024, next_remaining)
+        }
+    }
+}
+
+fn check_stone_has_even_number_of_digits(number: u64) -> bool {
+    number.ilog10() % 2 == 1
+}
+
+fn split_stone_into_halves(number: u64) -> (u64, u64) {
+    let number_as_string = number.to_string();
+    let half_length = number_as_string.len() / 2;
+    let (left, right) = number_as_string.split_at(half_length);
+    (left.parse().unwrap(), right.parse().unwrap())
+}
+
+/// The numbers of the stones on a single line, separated by spaces.
+pub fn parse_input(input: &str) -> Result<Vec<u64>, SyntaxError> {
+    parse_all(unsigned_list(' '), input)
+}
@@ -0,0 +1,49 @@
+use std::collections::HashMap;
+
+use aoc_common::{random::Rng, solution::Generate};
+use day_eleven::{parse_input, part_1, DayEleven};
+
+const CASES: u64 = 50;
+
+/// Blinks at all stones at once, counting how many there are of every number.
+fn reference(stones: &[u64], blinks: usize) -> u64 {
+    let mut counts: HashMap<u64, u64> = HashMap::new();
+    for stone in stones {
+        *counts.entry(*stone).or_default() += 1;
+    }
+
+    for _ in 0..blinks {
+        let mut next: HashMap<u64, u64> = HashMap::new();
+        for (stone, count) in counts {
+            let digits = stone.to_string();
+            let new_stones = if stone == 0 {
+                vec![1]
+            } else if digits.len() % 2 == 0 {
+                let (left, right) = digits.split_at(digits.len() / 2);
+                vec![left.parse().unwrap(), right.parse().unwrap()]
+            } else {
+                vec![stone * 2024]
+            };
+
+            for new_stone in new_stones {
+                *next.entry(new_stone).or_default() += count;
+            }
+        }
+        counts = next;
+    }
+
+    counts.values().sum()
+}
+
+#[test]
+fn stone_counts_match_the_reference() {
+    for seed in 0..CASES {
+        let stones = parse_input(&DayEleven::generate(&mut Rng::new(seed), 3)).unwrap();
+
+        assert_eq!(
+            part_1(&stones),
+            reference(&stones, 25),
+            "seed {seed}, {stones:?}"
+        );
+    }
+}
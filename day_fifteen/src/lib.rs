@@ -0,0 +1,187 @@
+use std::io;
+
+use aoc_common::{
+    error::SyntaxError,
+    input::InputFiles,
+    random::Rng,
+    solution::{Day, Generate, Solution, Visualize},
+    visualize::Visualizer,
+};
+use aoc_grid::Direction;
+use aoc_parse::{blank_line, parse_all};
+use winnow::combinator::separated_pair;
+
+use crate::{
+    parser::{parse_bot_directions, parse_warehouse},
+    warehouse::Warehouse,
+};
+
+pub mod parser;
+pub mod warehouse;
+
+pub const INPUT_FILES: InputFiles = InputFiles::new(env!("CARGO_MANIFEST_DIR"), "input_small.txt");
+
+pub const DAY: Day = Day::new::<DayFifteen>(15, &INPUT_FILES)
+    .with_visualization::<DayFifteen>()
+    .with_generator::<DayFifteen>();
+
+pub struct DayFifteen;
+
+impl Solution for DayFifteen {
+    type Input = AoCInput;
+    type PartOne = usize;
+    type PartTwo = usize;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne, Self::Error> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo, Self::Error> {
+        Ok(part_two(input))
+    }
+}
+
+impl Visualize for DayFifteen {
+    fn visualize(input: &Self::Input, visualizer: &mut Visualizer) -> io::Result<()> {
+        for (part, warehouse) in [
+            (1, input.warehouse.to_owned()),
+            (2, input.warehouse.widen()),
+        ] {
+            show_robot_moves(part, warehouse, &input.bot_directions, visualizer)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A walled warehouse of `size` by `size` tiles (at least three) with boxes, a few walls inside
+/// and the robot, followed by five moves of the robot per tile along a side.
+impl Generate for DayFifteen {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(3);
+        let mut tiles: Vec<Vec<char>> = (0..size)
+            .map(|y| {
+                (0..size)
+                    .map(|x| match rng.below(20) {
+                        _ if x == 0 || y == 0 || x == size - 1 || y == size - 1 => '#',
+                        0 => '#',
+                        1..=4 => 'O',
+                        _ => '.',
+                    })
+                    .collect()
+            })
+            .collect();
+        tiles[rng.index(size - 2) + 1][rng.index(size - 2) + 1] = '@';
+
+        let warehouse: String = tiles
+            .iter()
+            .map(|row| row.iter().collect::<String>() + "\n")
+            .collect();
+        let moves: Vec<u8> = (0..size * 5).map(|_| *rng.pick(b"^>v<")).collect();
+        let moves: Vec<&str> = moves
+            .chunks(70)
+            .map(|line| std::str::from_utf8(line).expect("Moves are ASCII"))
+            .collect();
+
+        format!("{warehouse}\n{}\n", moves.join("\n"))
+    }
+}
+
+/// Shows the warehouse after every move of the robot.
+fn show_robot_moves(
+    part: u8,
+    mut warehouse: Warehouse,
+    bot_directions: &[Direction],
+    visualizer: &mut Visualizer,
+) -> io::Result<()> {
+    let mut robot_location = warehouse
+        .get_bot_location()
+        .expect("Parsing checks there is a robot");
+
+    visualizer.show(
+        &warehouse
+            .frame()
+            .with_caption(format!("Part {part}, start")),
+    )?;
+    for (number, direction) in bot_directions.iter().enumerate() {
+        robot_location = warehouse.push(robot_location, direction);
+        let caption = format!(
+            "Part {part}, move {}/{}, GPS sum: {}",
+            number + 1,
+            bot_directions.len(),
+            warehouse.calc_gps_all_crates()
+        );
+        visualizer.show(&warehouse.frame().with_caption(caption))?;
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseError {
+    #[error("{0}")]
+    Syntax(#[from] SyntaxError),
+    #[error("there is no robot (@) in the warehouse")]
+    MissingRobot,
+}
+
+pub fn part_one(input: &AoCInput) -> usize {
+    move_robot(input.warehouse.to_owned(), &input.bot_directions)
+}
+
+pub fn part_two(input: &AoCInput) -> usize {
+    move_robot(input.warehouse.widen(), &input.bot_directions)
+}
+
+/// Moves the robot around the warehouse, returning the sum of GPS coordinates of the boxes afterwards
+pub fn move_robot(mut warehouse: Warehouse, bot_directions: &[Direction]) -> usize {
+    let mut robot_location = warehouse
+        .get_bot_location()
+        .expect("Parsing checks there is a robot");
+
+    // Go to each direction (specified in AoC input)
+    bot_directions.iter().for_each(|direction| {
+        // Try to push the robot in the given direction
+        robot_location = warehouse.push(robot_location, direction);
+    });
+
+    warehouse.calc_gps_all_crates()
+}
+
+pub fn parse_input(input: &str) -> Result<AoCInput, ParseError> {
+    // The warehouse and the moves of the robot are separated by an empty line
+    let (warehouse, bot_directions) = parse_all(
+        separated_pair(parse_warehouse, blank_line, parse_bot_directions),
+        input,
+    )?;
+
+    if warehouse.get_bot_location().is_none() {
+        return Err(ParseError::MissingRobot);
+    }
+
+    Ok(AoCInput {
+        warehouse,
+        bot_directions,
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct AoCInput {
+    pub warehouse: Warehouse,
+    pub bot_directions: Vec<Direction>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Content {
+    WideboxLeftPart,
+    WideBoxRightPart,
+    Box,
+    Empty,
+    Wall,
+    Robot,
+}
@@ -1,24 +1,50 @@
-use glam::IVec2;
+use aoc_common::visualize::{Cell, Color, Frame};
+use aoc_grid::{Direction, Grid, IVec2};
 
-use crate::{BotMove, Content};
+use crate::Content;
 
 #[derive(Debug, Clone)]
 pub struct Warehouse {
-    contents: Vec<Vec<Content>>,
-    width: usize,
-    height: usize,
+    contents: Grid<Content>,
 }
 impl Warehouse {
-    pub fn new(contents: Vec<Vec<Content>>, width: usize, height: usize) -> Self {
-        Self {
-            contents,
-            width,
-            height,
-        }
+    pub fn new(contents: Grid<Content>) -> Self {
+        Self { contents }
+    }
+
+    pub fn contents(&self) -> &Grid<Content> {
+        &self.contents
     }
 
-    pub fn push(&mut self, item_location: IVec2, direction: &BotMove) -> IVec2 {
-        let next_location = direction.get_next_vec(item_location);
+    /// Everything in the second warehouse is twice as wide, except for the robot.
+    pub fn widen(&self) -> Self {
+        let contents = Grid::from_fn(
+            self.contents.width() * 2,
+            self.contents.height(),
+            |location| {
+                let is_left_part = location.x % 2 == 0;
+                let original = &self.contents[IVec2::new(location.x / 2, location.y)];
+
+                match original {
+                    Content::Robot if is_left_part => Content::Robot,
+                    Content::Robot | Content::Empty => Content::Empty,
+                    Content::Wall => Content::Wall,
+                    Content::Box | Content::WideboxLeftPart | Content::WideBoxRightPart => {
+                        if is_left_part {
+                            Content::WideboxLeftPart
+                        } else {
+                            Content::WideBoxRightPart
+                        }
+                    }
+                }
+            },
+        );
+
+        Self::new(contents)
+    }
+
+    pub fn push(&mut self, item_location: IVec2, direction: &Direction) -> IVec2 {
+        let next_location = item_location + direction.vector();
 
         if self.can_push(next_location, direction) {
             self.push_unchecked(item_location, direction)
@@ -27,18 +53,22 @@ impl Warehouse {
         }
     }
 
-    fn push_unchecked(&mut self, item_location: IVec2, direction: &BotMove) -> IVec2 {
-        let next_location = direction.get_next_vec(item_location);
+    fn push_unchecked(&mut self, item_location: IVec2, direction: &Direction) -> IVec2 {
+        let next_location = item_location + direction.vector();
 
         // Check what content is at the next location
-        match &self.contents[next_location.y as usize][next_location.x as usize] {
+        match &self.contents[next_location] {
             // In specific cases moves will branch out
-            Content::WideboxLeftPart if matches!(direction, BotMove::Up | BotMove::Down) => {
+            Content::WideboxLeftPart
+                if matches!(direction, Direction::North | Direction::South) =>
+            {
                 self.push_wide_box_vertical(next_location, direction, IVec2::X);
                 self.move_item(item_location, next_location);
                 next_location
             }
-            Content::WideBoxRightPart if matches!(direction, BotMove::Up | BotMove::Down) => {
+            Content::WideBoxRightPart
+                if matches!(direction, Direction::North | Direction::South) =>
+            {
                 self.push_wide_box_vertical(next_location, direction, -IVec2::X);
                 self.move_item(item_location, next_location);
                 next_location
@@ -68,7 +98,7 @@ impl Warehouse {
     fn push_wide_box_vertical(
         &mut self,
         box_part_location: IVec2,
-        direction: &BotMove,
+        direction: &Direction,
         other_part_offset: IVec2,
     ) {
         // Recursively push both parts of the wide box starting with the immediatally touching part
@@ -79,16 +109,20 @@ impl Warehouse {
         self.push_unchecked(other_part_location, direction);
     }
 
-    fn can_push(&mut self, location: IVec2, direction: &BotMove) -> bool {
-        match &self.contents[location.y as usize][location.x as usize] {
+    fn can_push(&mut self, location: IVec2, direction: &Direction) -> bool {
+        match &self.contents[location] {
             // In specific cases checks will branch out
-            Content::WideboxLeftPart if matches!(direction, BotMove::Up | BotMove::Down) => {
-                let next_location = direction.get_next_vec(location);
+            Content::WideboxLeftPart
+                if matches!(direction, Direction::North | Direction::South) =>
+            {
+                let next_location = location + direction.vector();
                 self.can_push(next_location, direction)
                     && self.can_push(next_location + IVec2::X, direction)
             }
-            Content::WideBoxRightPart if matches!(direction, BotMove::Up | BotMove::Down) => {
-                let next_location = direction.get_next_vec(location);
+            Content::WideBoxRightPart
+                if matches!(direction, Direction::North | Direction::South) =>
+            {
+                let next_location = location + direction.vector();
                 self.can_push(next_location, direction)
                     && self.can_push(next_location - IVec2::X, direction)
             }
@@ -97,7 +131,7 @@ impl Warehouse {
             | Content::Box
             | Content::WideboxLeftPart
             | Content::WideBoxRightPart => {
-                let next_location = direction.get_next_vec(location);
+                let next_location = location + direction.vector();
                 self.can_push(next_location, direction)
             }
             Content::Empty => true,
@@ -106,62 +140,33 @@ impl Warehouse {
     }
 
     fn move_item(&mut self, source_location: IVec2, target_location: IVec2) {
-        let source_value = std::mem::replace(
-            &mut self.contents[source_location.y as usize][source_location.x as usize],
-            Content::Empty,
-        );
-        self.contents[target_location.y as usize][target_location.x as usize] = source_value;
+        let source_value = std::mem::replace(&mut self.contents[source_location], Content::Empty);
+        self.contents[target_location] = source_value;
     }
 
     pub fn calc_gps_all_crates(&self) -> usize {
-        (0..self.height)
-            .flat_map(|row_index| {
-                (0..self.width).filter_map(move |column_index| {
-                    if matches!(
-                        self.contents[row_index][column_index],
-                        Content::Box | Content::WideboxLeftPart
-                    ) {
-                        Some(row_index * 100 + column_index)
-                    } else {
-                        None
-                    }
-                })
-            })
+        self.contents
+            .iter()
+            .filter(|(_, content)| matches!(content, Content::Box | Content::WideboxLeftPart))
+            .map(|(location, _)| location.y as usize * 100 + location.x as usize)
             .sum()
     }
 
-    #[allow(dead_code)]
-    pub fn print(&self) {
-        for row in &self.contents {
-            for content in row {
-                print!(
-                    "{}",
-                    match content {
-                        Content::Box => 'O',
-                        Content::Empty => '.',
-                        Content::Wall => '#',
-                        Content::Robot => '@',
-                        Content::WideboxLeftPart => '[',
-                        Content::WideBoxRightPart => ']',
-                    }
-                );
-            }
-            println!();
-        }
+    pub fn frame(&self) -> Frame {
+        Frame::from_rows(self.contents.rows().map(|row| {
+            row.iter().map(|content| match content {
+                Content::Box => Cell::new('O', Color::Yellow),
+                Content::Empty => Cell::plain('.'),
+                Content::Wall => Cell::new('#', Color::Gray),
+                Content::Robot => Cell::new('@', Color::Red),
+                Content::WideboxLeftPart => Cell::new('[', Color::Yellow),
+                Content::WideBoxRightPart => Cell::new(']', Color::Yellow),
+            })
+        }))
     }
 
     pub fn get_bot_location(&self) -> Option<IVec2> {
         self.contents
-            .iter()
-            .enumerate()
-            .find_map(|(row_index, row)| {
-                row.iter().enumerate().find_map(|(col_index, col)| {
-                    if matches!(col, Content::Robot) {
-                        Some(IVec2::new(col_index as i32, row_index as i32))
-                    } else {
-                        None
-                    }
-                })
-            })
+            .find(|content| matches!(content, Content::Robot))
     }
 }
@@ -0,0 +1,105 @@
+use aoc_common::{random::Rng, solution::Generate};
+use day_fifteen::{parse_input, part_one, part_two, DayFifteen};
+
+const CASES: u64 = 40;
+
+/// Moves the robot around, finding everything a move pushes before moving any of it.
+fn reference_gps_sum(mut tiles: Vec<Vec<u8>>, moves: &str) -> usize {
+    let mut robot = (0..tiles.len())
+        .find_map(|y| Some((tiles[y].iter().position(|tile| *tile == b'@')?, y)))
+        .unwrap();
+
+    for (dx, dy) in moves.bytes().filter_map(|direction| match direction {
+        b'^' => Some((0, -1)),
+        b'>' => Some((1, 0)),
+        b'v' => Some((0, 1)),
+        b'<' => Some((-1, 0)),
+        _ => None,
+    }) {
+        let step = |(x, y): (usize, usize)| ((x as i32 + dx) as usize, (y as i32 + dy) as usize);
+        let mut pushed = vec![robot];
+        let mut index = 0;
+        let mut is_blocked = false;
+
+        while index < pushed.len() {
+            let next = step(pushed[index]);
+            index += 1;
+
+            let mut push = |location| {
+                if !pushed.contains(&location) {
+                    pushed.push(location);
+                }
+            };
+            match tiles[next.1][next.0] {
+                b'#' => is_blocked = true,
+                b'O' => push(next),
+                // Pushing a wide box up or down also pushes its other half
+                b'[' => {
+                    push(next);
+                    push((next.0 + 1, next.1));
+                }
+                b']' => {
+                    push(next);
+                    push((next.0 - 1, next.1));
+                }
+                _ => {}
+            }
+        }
+
+        if !is_blocked {
+            // Move the tiles furthest ahead first, so none of them is overwritten
+            for &(x, y) in pushed.iter().rev() {
+                let next = step((x, y));
+                tiles[next.1][next.0] = tiles[y][x];
+                tiles[y][x] = b'.';
+            }
+            robot = step(robot);
+        }
+    }
+
+    (0..tiles.len())
+        .flat_map(|y| (0..tiles[y].len()).map(move |x| (x, y)))
+        .filter(|&(x, y)| matches!(tiles[y][x], b'O' | b'['))
+        .map(|(x, y)| 100 * y + x)
+        .sum()
+}
+
+fn widen(tiles: &[Vec<u8>]) -> Vec<Vec<u8>> {
+    tiles
+        .iter()
+        .map(|row| {
+            row.iter()
+                .flat_map(|tile| match tile {
+                    b'O' => *b"[]",
+                    b'@' => *b"@.",
+                    other => [*other, *other],
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn gps_sums_match_the_reference() {
+    for seed in 0..CASES {
+        let input = DayFifteen::generate(&mut Rng::new(seed), 3 + seed as usize / 2);
+        let parsed = parse_input(&input).unwrap();
+
+        let (warehouse, moves) = input.split_once("\n\n").unwrap();
+        let tiles: Vec<Vec<u8>> = warehouse
+            .lines()
+            .map(|row| row.as_bytes().to_vec())
+            .collect();
+
+        assert_eq!(
+            part_one(&parsed),
+            reference_gps_sum(tiles.clone(), moves),
+            "seed {seed}\n{input}"
+        );
+        assert_eq!(
+            part_two(&parsed),
+            reference_gps_sum(widen(&tiles), moves),
+            "seed {seed}\n{input}"
+        );
+    }
+}
@@ -0,0 +1,188 @@
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::{
+    error::SyntaxError,
+    input::InputFiles,
+    random::Rng,
+    solution::{Day, Generate, Solution},
+};
+use aoc_parse::{blank_line, lines, parse_all, signed_list, signed_pair};
+use winnow::{
+    combinator::separated_pair,
+    error::{StrContext, StrContextValue},
+    Parser,
+};
+
+pub const INPUT_FILES: InputFiles = InputFiles::new(env!("CARGO_MANIFEST_DIR"), "input_small.txt");
+
+pub const DAY: Day = Day::new::<DayFive>(5, &INPUT_FILES).with_generator::<DayFive>();
+
+pub struct DayFive;
+
+impl Solution for DayFive {
+    type Input = PrintQueue;
+    type PartOne = i32;
+    type PartTwo = i32;
+    type Error = SyntaxError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne, Self::Error> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo, Self::Error> {
+        Ok(part_two(input))
+    }
+}
+
+/// `size` updates of an odd number of pages, some of them in the right order. Like the puzzle
+/// input, there is a rule for every two pages, so all pages of an update can be ordered.
+impl Generate for DayFive {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut pages: Vec<i64> = (10..100).collect();
+        rng.shuffle(&mut pages);
+        // The order of the pages, from first to last
+        pages.truncate(rng.range(5..40) as usize);
+
+        let mut rules: Vec<String> = pages
+            .iter()
+            .enumerate()
+            .flat_map(|(index, before)| pages[index + 1..].iter().map(move |after| (before, after)))
+            .map(|(before, after)| format!("{before}|{after}"))
+            .collect();
+        rng.shuffle(&mut rules);
+
+        let updates: Vec<String> = (0..size)
+            .map(|_| {
+                let mut update = pages.clone();
+                rng.shuffle(&mut update);
+                update.truncate(rng.range(1..(pages.len() as i64 + 1) / 2) as usize * 2 + 1);
+
+                if rng.chance(0.5) {
+                    update.sort_by_key(|page| pages.iter().position(|other| other == page));
+                }
+
+                let update: Vec<_> = update.iter().map(i64::to_string).collect();
+                update.join(",")
+            })
+            .collect();
+
+        format!("{}\n\n{}\n", rules.join("\n"), updates.join("\n"))
+    }
+}
+
+type OrderingRules = HashMap<i32, HashSet<i32>>;
+
+pub struct PrintQueue {
+    ordering_rules: OrderingRules,
+    update_list: Vec<Vec<UpdateNumber>>,
+}
+
+pub fn part_one(print_queue: &PrintQueue) -> i32 {
+    let total_middle_levels: i32 = print_queue
+        .update_list
+        .iter()
+        .filter_map(|update_line| {
+            let update_list_length = update_line.len();
+
+            let error_found = !print_queue.is_sorted(update_line);
+
+            // Return middle level (because this one must be summed for aoc answer)
+            if error_found {
+                None
+            } else {
+                Some(update_line[update_list_length / 2].number)
+            }
+        })
+        .sum();
+
+    total_middle_levels
+}
+
+pub fn part_two(print_queue: &PrintQueue) -> i32 {
+    let total_middle_levels: i32 = print_queue
+        .update_list
+        .iter()
+        .filter_map(|update_line| {
+            let update_list_length = update_line.len();
+
+            let error_found = !print_queue.is_sorted(update_line);
+
+            // Return middle level (because this one must be summed for aoc answer)
+            if error_found {
+                let mut sorted_line = update_line.clone();
+                sorted_line.sort_by(|a, b| print_queue.compare(a, b));
+                Some(sorted_line[update_list_length / 2].number)
+            } else {
+                None
+            }
+        })
+        .sum();
+
+    total_middle_levels
+}
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+struct UpdateNumber {
+    number: i32,
+}
+
+impl PrintQueue {
+    fn is_sorted(&self, update_line: &[UpdateNumber]) -> bool {
+        update_line.is_sorted_by(|a, b| self.compare(a, b) != Ordering::Greater)
+    }
+
+    fn compare(&self, number: &UpdateNumber, other: &UpdateNumber) -> Ordering {
+        if let Some(afters) = self.ordering_rules.get(&number.number) {
+            if afters.contains(&other.number) {
+                return Ordering::Less;
+            }
+        }
+
+        if let Some(afters) = self.ordering_rules.get(&other.number) {
+            if afters.contains(&number.number) {
+                return Ordering::Greater;
+            }
+        }
+
+        Ordering::Equal
+    }
+}
+
+impl From<i32> for UpdateNumber {
+    fn from(number: i32) -> Self {
+        Self { number }
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<PrintQueue, SyntaxError> {
+    let rule = signed_pair('|').context(StrContext::Expected(StrContextValue::Description(
+        "an ordering rule like 47|53",
+    )));
+    let update = signed_list(',').map(|pages: Vec<i32>| {
+        pages
+            .into_iter()
+            .map(UpdateNumber::from)
+            .collect::<Vec<_>>()
+    });
+
+    // The ordering rules and the updates are separated by an empty line
+    let (rules, update_list) = parse_all(
+        separated_pair(lines(rule), blank_line, lines(update)),
+        input,
+    )?;
+
+    let mut ordering_rules: OrderingRules = HashMap::new();
+    for (before, after) in rules {
+        ordering_rules.entry(before).or_default().insert(after);
+    }
+
+    Ok(PrintQueue {
+        ordering_rules,
+        update_list,
+    })
+}
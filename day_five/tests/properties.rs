@@ -0,0 +1,75 @@
+use aoc_common::{random::Rng, solution::Generate};
+use day_five::{parse_input, part_one, part_two, DayFive};
+
+const CASES: u64 = 50;
+
+fn split_input(input: &str) -> (Vec<(i32, i32)>, Vec<Vec<i32>>) {
+    let (rules, updates) = input.split_once("\n\n").unwrap();
+    let rules = rules
+        .lines()
+        .map(|rule| {
+            let (before, after) = rule.split_once('|').unwrap();
+            (before.parse().unwrap(), after.parse().unwrap())
+        })
+        .collect();
+    let updates = updates
+        .lines()
+        .map(|update| {
+            update
+                .split(',')
+                .map(|page| page.parse().unwrap())
+                .collect()
+        })
+        .collect();
+
+    (rules, updates)
+}
+
+/// The sum of the middle pages of the updates in the right order, and of the others once ordered.
+fn reference(rules: &[(i32, i32)], updates: &[Vec<i32>]) -> (i32, i32) {
+    let (mut ordered, mut reordered) = (0, 0);
+
+    for update in updates {
+        let must_precede = |before: i32, after: i32| rules.contains(&(before, after));
+        let is_ordered = (0..update.len()).all(|first| {
+            update[first + 1..]
+                .iter()
+                .all(|later| !must_precede(*later, update[first]))
+        });
+
+        // Once ordered, half of the other pages come before the middle page
+        let middle = update
+            .iter()
+            .find(|page| {
+                update
+                    .iter()
+                    .filter(|other| must_precede(**other, **page))
+                    .count()
+                    == update.len() / 2
+            })
+            .unwrap();
+
+        if is_ordered {
+            ordered += middle;
+        } else {
+            reordered += middle;
+        }
+    }
+
+    (ordered, reordered)
+}
+
+#[test]
+fn solutions_match_the_reference() {
+    for seed in 0..CASES {
+        let input = DayFive::generate(&mut Rng::new(seed), 20);
+        let queue = parse_input(&input).unwrap();
+        let (rules, updates) = split_input(&input);
+
+        assert_eq!(
+            (part_one(&queue), part_two(&queue)),
+            reference(&rules, &updates),
+            "seed {seed}"
+        );
+    }
+}
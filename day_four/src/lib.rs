@@ -0,0 +1,106 @@
+use std::collections::HashSet;
+
+use aoc_common::{
+    error::SyntaxError,
+    input::InputFiles,
+    random::Rng,
+    solution::{Day, Generate, Solution},
+};
+use aoc_grid::{Direction, Grid};
+use aoc_parse::{grid, parse_all};
+
+pub const INPUT_FILES: InputFiles = InputFiles::new(env!("CARGO_MANIFEST_DIR"), "input_small.txt");
+
+pub const DAY: Day = Day::new::<DayFour>(4, &INPUT_FILES).with_generator::<DayFour>();
+
+pub struct DayFour;
+
+impl Solution for DayFour {
+    type Input = Grid<char>;
+    type PartOne = usize;
+    type PartTwo = usize;
+    type Error = SyntaxError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne, Self::Error> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo, Self::Error> {
+        Ok(part_two(input))
+    }
+}
+
+/// A grid of random letters of `XMAS`, `size` wide and half as high.
+impl Generate for DayFour {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let letters = ['X', 'M', 'A', 'S'];
+
+        (0..size / 2 + 1)
+            .map(|_| {
+                let mut row: String = (0..size).map(|_| *rng.pick(&letters)).collect();
+                row.push('\n');
+                row
+            })
+            .collect()
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Grid<char>, SyntaxError> {
+    parse_all(grid(Some), input)
+}
+
+pub fn part_one(input: &Grid<char>) -> usize {
+    static XMAS: &str = "XMAS";
+
+    input
+        .positions()
+        .map(|position| {
+            Direction::ALL
+                .iter()
+                .filter(|heading| {
+                    let mut input_character_iterator = input
+                        .ray(position, heading.vector())
+                        .map(|(_, character)| *character);
+
+                    XMAS.chars()
+                        .all(|character| input_character_iterator.next() == Some(character))
+                })
+                .count()
+        })
+        .sum()
+}
+
+pub fn part_two(input: &Grid<char>) -> usize {
+    static MAS: &str = "MAS";
+
+    let mut found = HashSet::new();
+    let mut found_multiple_times = vec![];
+
+    for position in input.positions() {
+        Direction::DIAGONAL.iter().for_each(|heading| {
+            let mut character_iterator = input.ray(position, heading.vector()).map(|(_, c)| *c);
+
+            let failed_match = MAS
+                .chars()
+                .any(|character| character_iterator.next() != Some(character));
+
+            if !failed_match {
+                // If we've found a word, we are actually interested in the second character ('A'),
+                // because that is the middle of the two 'MAS' crossing each other.
+                let second_character_index = position + heading.vector();
+
+                let first_insert = found.insert(second_character_index);
+
+                if !first_insert {
+                    found_multiple_times.push(second_character_index);
+                }
+            }
+        });
+    }
+
+    found_multiple_times.len()
+}
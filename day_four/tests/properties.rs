@@ -0,0 +1,57 @@
+use aoc_common::{random::Rng, solution::Generate};
+use day_four::{parse_input, part_one, part_two, DayFour};
+
+const CASES: u64 = 50;
+
+fn letter(rows: &[&[u8]], x: i32, y: i32) -> Option<u8> {
+    let row = rows.get(usize::try_from(y).ok()?)?;
+    row.get(usize::try_from(x).ok()?).copied()
+}
+
+fn reference_xmas(rows: &[&[u8]]) -> usize {
+    let mut count = 0;
+
+    for y in 0..rows.len() as i32 {
+        for x in 0..rows[0].len() as i32 {
+            for (dx, dy) in (-1..=1).flat_map(|dx| (-1..=1).map(move |dy| (dx, dy))) {
+                let word: Vec<_> = (0..4)
+                    .map_while(|step| letter(rows, x + dx * step, y + dy * step))
+                    .collect();
+                count += (word == b"XMAS") as usize;
+            }
+        }
+    }
+
+    count
+}
+
+/// Every `A` with `M` and `S` at both ends of both diagonals.
+fn reference_x_mas(rows: &[&[u8]]) -> usize {
+    let is_mas = |first, second| {
+        matches!(
+            (first, second),
+            (Some(b'M'), Some(b'S')) | (Some(b'S'), Some(b'M'))
+        )
+    };
+
+    (0..rows.len() as i32)
+        .flat_map(|y| (0..rows[0].len() as i32).map(move |x| (x, y)))
+        .filter(|&(x, y)| {
+            letter(rows, x, y) == Some(b'A')
+                && is_mas(letter(rows, x - 1, y - 1), letter(rows, x + 1, y + 1))
+                && is_mas(letter(rows, x + 1, y - 1), letter(rows, x - 1, y + 1))
+        })
+        .count()
+}
+
+#[test]
+fn solutions_match_the_reference() {
+    for seed in 0..CASES {
+        let input = DayFour::generate(&mut Rng::new(seed), 1 + seed as usize);
+        let grid = parse_input(&input).unwrap();
+        let rows: Vec<&[u8]> = input.lines().map(str::as_bytes).collect();
+
+        assert_eq!(part_one(&grid), reference_xmas(&rows), "seed {seed}");
+        assert_eq!(part_two(&grid), reference_x_mas(&rows), "seed {seed}");
+    }
+}
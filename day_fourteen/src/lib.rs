@@ -0,0 +1,499 @@
+use std::{collections::HashSet, io};
+
+use aoc_common::{
+    error::SyntaxError,
+    image::{Image, Rgb},
+    input::InputFiles,
+    random::Rng,
+    solution::{Day, Draw, Generate, Solution, Visualize},
+    visualize::{Cell, Color, Frame, Visualizer},
+};
+use aoc_parse::{lines, parse_all, signed_pair};
+use glam::IVec2;
+use winnow::{Parser, Result};
+
+pub const INPUT_FILES: InputFiles = InputFiles::new(env!("CARGO_MANIFEST_DIR"), "input_small.txt");
+
+pub const DAY: Day = Day::new::<DayFourteen>(14, &INPUT_FILES)
+    .with_visualization::<DayFourteen>()
+    .with_image::<DayFourteen>()
+    .with_generator::<DayFourteen>();
+
+pub struct DayFourteen;
+
+impl Solution for DayFourteen {
+    type Input = Vec<SecurityBotConfig>;
+    type PartOne = usize;
+    type PartTwo = usize;
+    type Error = SyntaxError;
+
+    fn parse(input: &str) -> std::result::Result<Self::Input, Self::Error> {
+        parse_restroom_bots(input)
+    }
+
+    fn part_one(input: &Self::Input) -> std::result::Result<Self::PartOne, Self::Error> {
+        Ok(part_1(input))
+    }
+
+    fn part_two(input: &Self::Input) -> std::result::Result<Self::PartTwo, Self::Error> {
+        Ok(part_2(input))
+    }
+}
+
+impl Visualize for DayFourteen {
+    /// Shows every candidate frame, the picture of a christmas tree should appear among them.
+    fn visualize(input: &Self::Input, visualizer: &mut Visualizer) -> io::Result<()> {
+        for (index, locations) in candidate_frames(input).iter().enumerate() {
+            let frame = Frame::from_rows((0..ROOM_TALL).map(|y| {
+                (0..ROOM_WIDE).map(move |x| {
+                    if locations.contains(&IVec2 { x, y }) {
+                        Cell::new('+', Color::Green)
+                    } else {
+                        Cell::plain(' ')
+                    }
+                })
+            }))
+            .with_caption(format!(
+                "Seconds: {}",
+                index * CYCLE + FIRST_NON_RANDOM_LOOKING_OUTPUT_SECONDS as usize
+            ));
+            visualizer.show(&frame)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Draw for DayFourteen {
+    /// Draws the robots at the given second, by default when they show the christmas tree.
+    fn draw(input: &Self::Input, moment: Option<usize>) -> std::result::Result<Image, Self::Error> {
+        let seconds = moment.unwrap_or_else(|| part_2(input));
+        // The robots are back where they started every `ROOM_WIDE * ROOM_TALL` seconds
+        let seconds = (seconds % (ROOM_WIDE * ROOM_TALL) as usize) as i32;
+        let locations: HashSet<IVec2> = input
+            .iter()
+            .map(|bot| calculate_location_after_simulation(bot, seconds, ROOM_WIDE, ROOM_TALL))
+            .collect();
+
+        Ok(Image::from_fn(
+            ROOM_WIDE as usize,
+            ROOM_TALL as usize,
+            |x, y| {
+                if locations.contains(&IVec2::new(x as i32, y as i32)) {
+                    // Dark enough to stay black in a bitmap
+                    Rgb::new(20, 110, 40)
+                } else {
+                    Rgb::WHITE
+                }
+            },
+        ))
+    }
+}
+
+/// `size` robots anywhere in the room, moving at most a hundred tiles a second either way.
+/// The robots are random, so they don't show a christmas tree at any moment.
+impl Generate for DayFourteen {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                format!(
+                    "p={},{} v={},{}\n",
+                    rng.range(0..ROOM_WIDE as i64),
+                    rng.range(0..ROOM_TALL as i64),
+                    rng.range(-100..101),
+                    rng.range(-100..101)
+                )
+            })
+            .collect()
+    }
+}
+
+pub const ROOM_WIDE: i32 = 101;
+pub const ROOM_TALL: i32 = 103;
+const AMOUNT_OF_SECONDS_SIMULATED: i32 = 100;
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct BotsInQuadrantCount {
+    top_left: usize,
+    top_right: usize,
+    bottom_left: usize,
+    bottom_right: usize,
+}
+
+impl BotsInQuadrantCount {
+    fn increase(&mut self, location: &IVec2) {
+        const MIDDLE_HORIZONTALY: i32 = (ROOM_WIDE - 1) / 2;
+        const MIDDLE_VERTICALLY: i32 = (ROOM_TALL - 1) / 2;
+        let left = location.x < MIDDLE_HORIZONTALY;
+        let right = location.x > MIDDLE_HORIZONTALY;
+        let top = location.y < MIDDLE_VERTICALLY;
+        let bottom = location.y > MIDDLE_VERTICALLY;
+
+        match (top, right, bottom, left) {
+            (true, false, false, true) => self.top_left += 1,
+            (true, true, false, false) => self.top_right += 1,
+            (false, false, true, true) => self.bottom_left += 1,
+            (false, true, true, false) => self.bottom_right += 1,
+            _ => (),
+        }
+    }
+}
+
+pub fn part_1(bots: &[SecurityBotConfig]) -> usize {
+    let mut count = BotsInQuadrantCount::default();
+
+    bots.iter()
+        .map(|bot| {
+            calculate_location_after_simulation(
+                bot,
+                AMOUNT_OF_SECONDS_SIMULATED,
+                ROOM_WIDE,
+                ROOM_TALL,
+            )
+        })
+        .for_each(|location| count.increase(&location));
+
+    count.top_left * count.top_right * count.bottom_left * count.bottom_right
+}
+
+pub fn part_2(bots: &[SecurityBotConfig]) -> usize {
+    // I admit. I didn't really solve it, but wanted to SEE the solution.
+    // I saw there was some cyclic action going on, noticed when frames appeared which looked non-random.
+    // For my input, the first noisy "image" appeared at 27 seconds. A simular (bit slightly different)
+    // picture appeared at 130. This repeated every 103 seconds.
+    // with the "script" now in `visualize` (`--visualize`) I just repeated this until I saw a christmas appearing (pressing ctrl+c) and reading the number
+    //
+    // a "boring" programming only solution would have been to just see which frame had most connected bots/the least amount of separate groups of bots
+    // sooo lets check when most of the dots are connected and return that number
+    let (most_connected_frame, _) = candidate_frames(bots)
+        .iter()
+        .enumerate()
+        .max_by_key(|(_, locations)| count_connected_bots(locations))
+        .expect("There should be at least one candidate frame");
+
+    most_connected_frame * CYCLE + FIRST_NON_RANDOM_LOOKING_OUTPUT_SECONDS as usize
+}
+
+const FIRST_NON_RANDOM_LOOKING_OUTPUT_SECONDS: i32 = 27;
+const CYCLE: usize = 103;
+
+fn candidate_frames(bots: &[SecurityBotConfig]) -> Vec<HashSet<IVec2>> {
+    // 10403 was choses as maximum, because I calculated the cycle repeated every 10403 seconds
+    // after this cycle you got perfectly identical images.
+    (FIRST_NON_RANDOM_LOOKING_OUTPUT_SECONDS..10403)
+        .step_by(CYCLE)
+        .map(|seconds| {
+            bots.iter()
+                .map(|bot| calculate_location_after_simulation(bot, seconds, ROOM_WIDE, ROOM_TALL))
+                .collect()
+        })
+        .collect()
+}
+
+fn count_connected_bots(locations: &HashSet<IVec2>) -> usize {
+    locations
+        .iter()
+        .filter(|location| {
+            [IVec2::X, IVec2::Y, -IVec2::X, -IVec2::Y]
+                .iter()
+                .any(|offset| locations.contains(&(**location + *offset)))
+        })
+        .count()
+}
+
+pub fn calculate_location_after_simulation(
+    bot: &SecurityBotConfig,
+    iterations: i32,
+    x_size: i32,
+    y_size: i32,
+) -> IVec2 {
+    // A bot is back in the same column every `x_size` iterations (and row every `y_size`), so
+    // skipping those keeps many iterations from overflowing
+    let iterations = IVec2::new(iterations.rem_euclid(x_size), iterations.rem_euclid(y_size));
+    let new_location: IVec2 = bot.location + bot.speed * iterations;
+
+    // Before I discovered rem_euclid, this also works very well!
+    // let wrapped_x = ((new_location.x % x_size) + x_size) % x_size;
+    // let wrapped_y = ((new_location.y % y_size) + y_size) % y_size;
+
+    new_location.rem_euclid(IVec2 {
+        x: x_size,
+        y: y_size,
+    })
+}
+
+pub fn parse_restroom_bots(
+    input: &str,
+) -> std::result::Result<Vec<SecurityBotConfig>, SyntaxError> {
+    parse_all(lines(parse_bot), input)
+}
+
+pub fn parse_bot(input: &mut &str) -> Result<SecurityBotConfig> {
+    let (_, location, _, speed) = ("p=", parse_ivec2, " v=", parse_ivec2).parse_next(input)?;
+
+    Ok(SecurityBotConfig { location, speed })
+}
+
+fn parse_ivec2(input: &mut &str) -> Result<IVec2> {
+    signed_pair(',').map(IVec2::from).parse_next(input)
+}
+
+#[derive(Debug)]
+pub struct SecurityBotConfig {
+    pub location: IVec2,
+    pub speed: IVec2,
+}
+
+#[cfg(test)]
+mod test {
+    use glam::IVec2;
+
+    use crate::{
+        calculate_location_after_simulation, BotsInQuadrantCount, SecurityBotConfig, ROOM_TALL,
+        ROOM_WIDE,
+    };
+
+    #[test]
+    fn test_location_without_wrap() {
+        let bot = SecurityBotConfig {
+            location: IVec2 { x: 5, y: 20 },
+            speed: IVec2 { x: 3, y: -3 },
+        };
+
+        let expected_sim_location = IVec2 { x: 8, y: 17 };
+
+        let sim_located = calculate_location_after_simulation(&bot, 1, ROOM_WIDE, ROOM_TALL);
+        assert_eq!(expected_sim_location, sim_located)
+    }
+
+    #[test]
+    fn test_location_overflow_wrap() {
+        let bot = SecurityBotConfig {
+            location: IVec2 { x: 0, y: 0 },
+            speed: IVec2 {
+                x: ROOM_WIDE,
+                y: ROOM_TALL,
+            },
+        };
+
+        let sim_located = calculate_location_after_simulation(&bot, 1, ROOM_WIDE, ROOM_TALL);
+        assert_eq!(bot.location, sim_located)
+    }
+
+    #[test]
+    fn test_location_overflow_and_more_wrap() {
+        let bot = SecurityBotConfig {
+            location: IVec2 { x: 0, y: 0 },
+            speed: IVec2 {
+                x: ROOM_WIDE + 1,
+                y: ROOM_TALL + 1,
+            },
+        };
+
+        let expected_sim_location = IVec2 { x: 1, y: 1 };
+
+        let sim_located = calculate_location_after_simulation(&bot, 1, ROOM_WIDE, ROOM_TALL);
+        assert_eq!(expected_sim_location, sim_located)
+    }
+
+    #[test]
+    fn test_location_overflow_but_more_wrap() {
+        let bot = SecurityBotConfig {
+            location: IVec2 { x: 0, y: 0 },
+            speed: IVec2 {
+                x: 2 * ROOM_WIDE + 1,
+                y: 2 * ROOM_TALL + 1,
+            },
+        };
+
+        let expected_sim_location = IVec2 { x: 1, y: 1 };
+
+        let sim_located = calculate_location_after_simulation(&bot, 1, ROOM_WIDE, ROOM_TALL);
+        assert_eq!(expected_sim_location, sim_located)
+    }
+
+    #[test]
+    fn test_location_underflow_wrap() {
+        let bot = SecurityBotConfig {
+            location: IVec2 { x: 0, y: 0 },
+            speed: IVec2 {
+                x: -ROOM_WIDE,
+                y: -ROOM_TALL,
+            },
+        };
+
+        let sim_located = calculate_location_after_simulation(&bot, 1, ROOM_WIDE, ROOM_TALL);
+        assert_eq!(bot.location, sim_located)
+    }
+
+    #[test]
+    fn test_location_underflow_and_less_wrap() {
+        let bot = SecurityBotConfig {
+            location: IVec2 { x: 0, y: 0 },
+            speed: IVec2 {
+                x: -ROOM_WIDE - 1,
+                y: -ROOM_TALL - 1,
+            },
+        };
+
+        let expected_sim_location = IVec2 {
+            x: ROOM_WIDE - 2,
+            y: ROOM_TALL - 2,
+        };
+
+        let sim_located = calculate_location_after_simulation(&bot, 2, ROOM_WIDE, ROOM_TALL);
+        assert_eq!(expected_sim_location, sim_located)
+    }
+
+    #[test]
+    fn test_location_underflow_but_more_wrap() {
+        let bot = SecurityBotConfig {
+            location: IVec2 { x: 0, y: 0 },
+            speed: IVec2 {
+                x: -2 * ROOM_WIDE + 1,
+                y: -2 * ROOM_TALL + 1,
+            },
+        };
+
+        let expected_sim_location = IVec2 { x: 1, y: 1 };
+
+        let sim_located = calculate_location_after_simulation(&bot, 1, ROOM_WIDE, ROOM_TALL);
+        assert_eq!(expected_sim_location, sim_located)
+    }
+
+    #[test]
+    fn test_location_without_wrap_multiple() {
+        let bot = SecurityBotConfig {
+            location: IVec2 { x: 5, y: 30 },
+            speed: IVec2 { x: 3, y: -3 },
+        };
+
+        let expected_sim_location = IVec2 { x: 35, y: 0 };
+
+        let sim_located = calculate_location_after_simulation(&bot, 10, ROOM_WIDE, ROOM_TALL);
+        assert_eq!(expected_sim_location, sim_located)
+    }
+
+    #[test]
+    fn test_location_overflow_wrap_multiple() {
+        let bot = SecurityBotConfig {
+            location: IVec2 { x: 0, y: 0 },
+            speed: IVec2 {
+                x: ROOM_WIDE,
+                y: ROOM_TALL,
+            },
+        };
+
+        let sim_located = calculate_location_after_simulation(&bot, 10, ROOM_WIDE, ROOM_TALL);
+        assert_eq!(bot.location, sim_located)
+    }
+
+    #[test]
+    fn test_location_underflow_wrap_multiple() {
+        let bot = SecurityBotConfig {
+            location: IVec2 { x: 0, y: 0 },
+            speed: IVec2 {
+                x: -ROOM_WIDE,
+                y: -ROOM_TALL,
+            },
+        };
+
+        let sim_located = calculate_location_after_simulation(&bot, 10, ROOM_WIDE, ROOM_TALL);
+        assert_eq!(bot.location, sim_located)
+    }
+
+    #[test]
+    fn test_location_aoc_example() {
+        let bot = SecurityBotConfig {
+            location: IVec2 { x: 2, y: 4 },
+            speed: IVec2 { x: 2, y: -3 },
+        };
+
+        let expected_location = IVec2 { x: 1, y: 3 };
+
+        let sim_located = calculate_location_after_simulation(&bot, 5, 11, 7);
+        assert_eq!(expected_location, sim_located)
+    }
+
+    #[test]
+    fn test_left_top_quadrant() {
+        let location = IVec2 { x: 49, y: 50 };
+        let mut count = BotsInQuadrantCount::default();
+        let expected_count = BotsInQuadrantCount {
+            top_left: 1,
+            ..Default::default()
+        };
+
+        count.increase(&location);
+
+        assert_eq!(expected_count, count);
+    }
+
+    #[test]
+    fn test_right_top_quadrant() {
+        let location = IVec2 { x: 51, y: 50 };
+        let mut count = BotsInQuadrantCount::default();
+        let expected_count = BotsInQuadrantCount {
+            top_right: 1,
+            ..Default::default()
+        };
+
+        count.increase(&location);
+
+        assert_eq!(expected_count, count);
+    }
+
+    #[test]
+    fn test_left_bottom_quadrant() {
+        let location = IVec2 { x: 49, y: 52 };
+        let mut count = BotsInQuadrantCount::default();
+        let expected_count = BotsInQuadrantCount {
+            bottom_left: 1,
+            ..Default::default()
+        };
+
+        count.increase(&location);
+
+        assert_eq!(expected_count, count);
+    }
+
+    #[test]
+    fn test_right_bottom_quadrant() {
+        let location = IVec2 { x: 51, y: 52 };
+        let mut count = BotsInQuadrantCount::default();
+        let expected_count = BotsInQuadrantCount {
+            bottom_right: 1,
+            ..Default::default()
+        };
+
+        count.increase(&location);
+
+        assert_eq!(expected_count, count);
+    }
+
+    #[test]
+    fn test_quadrant_horizontal_center_ignored() {
+        let location = IVec2 { x: 50, y: 0 };
+        let mut count = BotsInQuadrantCount::default();
+        let expected_count = BotsInQuadrantCount {
+            ..Default::default()
+        };
+
+        count.increase(&location);
+
+        assert_eq!(expected_count, count);
+    }
+
+    #[test]
+    fn test_quadrant_vertical_center_ignored() {
+        let location = IVec2 { x: 0, y: 51 };
+        let mut count = BotsInQuadrantCount::default();
+        let expected_count = BotsInQuadrantCount {
+            ..Default::default()
+        };
+
+        count.increase(&location);
+
+        assert_eq!(expected_count, count);
+    }
+}
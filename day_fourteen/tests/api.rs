@@ -0,0 +1,50 @@
+use aoc_common::solution::Draw;
+use day_fourteen::{
+    calculate_location_after_simulation, parse_restroom_bots, DayFourteen, ROOM_TALL, ROOM_WIDE,
+};
+use glam::IVec2;
+
+#[test]
+fn example_bot_wraps_around_the_room() {
+    let bots = parse_restroom_bots("p=2,4 v=2,-3\n").unwrap();
+
+    let locations: Vec<_> = (1..=5)
+        .map(|seconds| calculate_location_after_simulation(&bots[0], seconds, 11, 7))
+        .collect();
+
+    assert_eq!(
+        locations,
+        [
+            IVec2::new(4, 1),
+            IVec2::new(6, 5),
+            IVec2::new(8, 2),
+            IVec2::new(10, 6),
+            IVec2::new(1, 3)
+        ]
+    );
+}
+
+#[test]
+fn parses_every_example_bot() {
+    let bots = parse_restroom_bots(include_str!("../input_small.txt")).unwrap();
+
+    assert_eq!(bots.len(), 12);
+    assert_eq!(bots[0].location, IVec2::new(0, 4));
+    assert_eq!(bots[0].speed, IVec2::new(3, -3));
+}
+
+#[test]
+fn many_seconds_do_not_overflow() {
+    let bots = parse_restroom_bots(include_str!("../input_small.txt")).unwrap();
+    let seconds = 100_000_000;
+    let same_seconds = seconds % (ROOM_WIDE * ROOM_TALL) as usize;
+
+    assert_eq!(
+        calculate_location_after_simulation(&bots[0], seconds as i32, 11, 7),
+        calculate_location_after_simulation(&bots[0], seconds as i32 % 77, 11, 7)
+    );
+    assert_eq!(
+        DayFourteen::draw(&bots, Some(seconds)),
+        DayFourteen::draw(&bots, Some(same_seconds))
+    );
+}
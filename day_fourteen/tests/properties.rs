@@ -0,0 +1,48 @@
+use aoc_common::{random::Rng, solution::Generate};
+use day_fourteen::{parse_restroom_bots, part_1, DayFourteen, ROOM_TALL, ROOM_WIDE};
+
+const CASES: u64 = 50;
+
+/// Moves every robot a second at a time, wrapping around at the walls.
+fn reference_safety_factor(input: &str) -> usize {
+    let mut quadrants = [0; 4];
+
+    for line in input.lines() {
+        let numbers: Vec<i32> = line
+            .split(|c: char| !c.is_ascii_digit() && c != '-')
+            .filter(|number| !number.is_empty())
+            .map(|number| number.parse().unwrap())
+            .collect();
+        let [mut x, mut y, dx, dy] = numbers[..] else {
+            panic!("A robot has a location and a speed: {line}");
+        };
+
+        for _ in 0..100 {
+            x = (x + dx + ROOM_WIDE) % ROOM_WIDE;
+            y = (y + dy + ROOM_TALL) % ROOM_TALL;
+        }
+
+        let (middle_x, middle_y) = (ROOM_WIDE / 2, ROOM_TALL / 2);
+        if x != middle_x && y != middle_y {
+            quadrants[(x > middle_x) as usize + 2 * (y > middle_y) as usize] += 1;
+        }
+    }
+
+    quadrants.iter().product()
+}
+
+// Part two looks for the christmas tree in the frames where it shows up for the puzzle input,
+// random robots never show it, so only part one is compared.
+#[test]
+fn safety_factor_matches_the_reference() {
+    for seed in 0..CASES {
+        let input = DayFourteen::generate(&mut Rng::new(seed), 1 + seed as usize * 10);
+        let bots = parse_restroom_bots(&input).unwrap();
+
+        assert_eq!(
+            part_1(&bots),
+            reference_safety_factor(&input),
+            "seed {seed}"
+        );
+    }
+}
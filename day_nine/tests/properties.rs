@@ -0,0 +1,81 @@
+use aoc_common::{random::Rng, solution::Generate};
+use day_nine::{parse_input, part_one, part_two, DayNine};
+
+const CASES: u64 = 50;
+
+/// Every block of the disk, with the id of the file using it.
+fn blocks(disk_map: &[u8]) -> Vec<Option<usize>> {
+    disk_map
+        .iter()
+        .enumerate()
+        .flat_map(|(index, length)| {
+            let file_id = (index % 2 == 0).then_some(index / 2);
+            std::iter::repeat_n(file_id, *length as usize)
+        })
+        .collect()
+}
+
+fn checksum(blocks: &[Option<usize>]) -> u64 {
+    blocks
+        .iter()
+        .enumerate()
+        .map(|(position, file_id)| (position * file_id.unwrap_or(0)) as u64)
+        .sum()
+}
+
+/// Moves the last file block to the first free block, until the free blocks are all at the end.
+fn reference_block_moves(mut blocks: Vec<Option<usize>>) -> u64 {
+    while let Some(free) = blocks.iter().position(Option::is_none) {
+        let last = blocks.pop().unwrap();
+        if free < blocks.len() {
+            blocks[free] = last;
+        } else if last.is_some() {
+            blocks.push(last);
+            break;
+        }
+    }
+
+    checksum(&blocks)
+}
+
+/// Moves whole files, starting with the highest id, to the first free span large enough.
+fn reference_file_moves(mut blocks: Vec<Option<usize>>) -> u64 {
+    let highest_id = blocks.iter().flatten().max().copied().unwrap_or(0);
+
+    for file_id in (0..=highest_id).rev() {
+        let start = blocks.iter().position(|id| *id == Some(file_id)).unwrap();
+        let length = blocks.iter().filter(|id| **id == Some(file_id)).count();
+
+        let free_start = (0..start).find(|free_start| {
+            blocks[*free_start..*free_start + length]
+                .iter()
+                .all(Option::is_none)
+        });
+        if let Some(free_start) = free_start {
+            blocks[free_start..free_start + length].fill(Some(file_id));
+            blocks[start..start + length].fill(None);
+        }
+    }
+
+    checksum(&blocks)
+}
+
+#[test]
+fn solutions_match_the_reference() {
+    for seed in 0..CASES {
+        let input = DayNine::generate(&mut Rng::new(seed), 1 + seed as usize);
+        let disk_map = parse_input(&input).unwrap();
+        let blocks = blocks(&disk_map);
+
+        assert_eq!(
+            part_one(&disk_map),
+            reference_block_moves(blocks.clone()),
+            "seed {seed}, {input}"
+        );
+        assert_eq!(
+            part_two(&disk_map),
+            reference_file_moves(blocks),
+            "seed {seed}, {input}"
+        );
+    }
+}
@@ -0,0 +1,232 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde::Serialize;
+
+use crate::InputNumbers;
+
+/// The widest bar of the histogram in the text form.
+const HISTOGRAM_WIDTH: usize = 40;
+
+/// Where the answers for the first two lists come from: the pairs and IDs adding the most to
+/// them, and how the differences between the pairs are spread.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Explanation {
+    pub distance: i64,
+    pub similarity: i64,
+    pub pairs: usize,
+    /// The pairs with the largest difference, largest first
+    pub top_pairs: Vec<Pair>,
+    /// The IDs adding the most to the similarity, most first
+    pub top_ids: Vec<Similar>,
+    pub histogram: Vec<Bucket>,
+}
+
+/// The `rank`-th smallest locations of both lists (counting from one), paired for the distance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Pair {
+    pub rank: usize,
+    pub first: i32,
+    pub second: i32,
+    pub difference: i64,
+}
+
+/// An ID in both lists, adding the ID for every combination of its occurances to the similarity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Similar {
+    pub id: i32,
+    pub occurances_first: usize,
+    pub occurances_second: usize,
+    pub contribution: i64,
+}
+
+/// The number of pairs with a difference from `min` up to and including `max`. Every bucket
+/// after the first (of zero) is ten times as wide as the one before.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Bucket {
+    pub min: i64,
+    pub max: i64,
+    pub pairs: usize,
+}
+
+impl Explanation {
+    /// Explains the answers of the first two lists, with at most `top` pairs and IDs.
+    pub fn new(input: &InputNumbers, top: usize) -> Self {
+        let (first, second) = (&input.lists[0], &input.lists[1]);
+
+        let pairs: Vec<Pair> = first
+            .iter()
+            .zip(second)
+            .enumerate()
+            .map(|(index, (first, second))| Pair {
+                rank: index + 1,
+                first: *first,
+                second: *second,
+                difference: (*first as i64 - *second as i64).abs(),
+            })
+            .collect();
+
+        let mut similar: Vec<Similar> = input.counted_occurances[0]
+            .iter()
+            .filter_map(|(id, occurances_first)| {
+                let occurances_second = *input.counted_occurances[1].get(id)?;
+                Some(Similar {
+                    id: *id,
+                    occurances_first: *occurances_first,
+                    occurances_second,
+                    contribution: *id as i64 * (occurances_first * occurances_second) as i64,
+                })
+            })
+            .collect();
+
+        let distance = pairs.iter().map(|pair| pair.difference).sum();
+        let similarity = similar.iter().map(|similar| similar.contribution).sum();
+        let histogram = histogram(&pairs);
+
+        let mut top_pairs = pairs.clone();
+        // The sort is stable, so equal differences stay in order of rank
+        top_pairs.sort_by_key(|pair| std::cmp::Reverse(pair.difference));
+        top_pairs.truncate(top);
+
+        // IDs can be negative, which makes them count against the similarity just as much
+        similar.sort_unstable_by_key(|similar| {
+            (std::cmp::Reverse(similar.contribution.abs()), similar.id)
+        });
+        similar.truncate(top);
+
+        Self {
+            distance,
+            similarity,
+            pairs: pairs.len(),
+            top_pairs,
+            top_ids: similar,
+            histogram,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("An explanation only contains plain data")
+    }
+}
+
+/// Buckets from zero up to the largest difference, including the empty ones in between.
+fn histogram(pairs: &[Pair]) -> Vec<Bucket> {
+    let largest = pairs.iter().map(|pair| pair.difference).max().unwrap_or(0);
+
+    let mut buckets = vec![Bucket {
+        min: 0,
+        max: 0,
+        pairs: 0,
+    }];
+    while buckets.last().unwrap().max < largest {
+        let min = buckets.last().unwrap().max + 1;
+        buckets.push(Bucket {
+            min,
+            max: min * 10 - 1,
+            pairs: 0,
+        });
+    }
+
+    for pair in pairs {
+        let bucket = buckets.partition_point(|bucket| bucket.max < pair.difference);
+        buckets[bucket].pairs += 1;
+    }
+    buckets
+}
+
+impl Display for Explanation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Distance {} over {} pairs", self.distance, self.pairs)?;
+        if !self.top_pairs.is_empty() {
+            writeln!(
+                f,
+                "{:>8}{:>12}{:>12}{:>12}",
+                "rank", "list 1", "list 2", "difference"
+            )?;
+        }
+        for pair in &self.top_pairs {
+            writeln!(
+                f,
+                "{:>8}{:>12}{:>12}{:>12}",
+                pair.rank, pair.first, pair.second, pair.difference
+            )?;
+        }
+
+        writeln!(f, "\nSimilarity {}", self.similarity)?;
+        if !self.top_ids.is_empty() {
+            writeln!(
+                f,
+                "{:>12}{:>8}{:>8}{:>14}",
+                "id", "list 1", "list 2", "contribution"
+            )?;
+        }
+        for similar in &self.top_ids {
+            writeln!(
+                f,
+                "{:>12}{:>8}{:>8}{:>14}",
+                similar.id,
+                similar.occurances_first,
+                similar.occurances_second,
+                similar.contribution
+            )?;
+        }
+
+        write!(f, "\nDifferences")?;
+        let most = self.histogram.iter().map(|bucket| bucket.pairs).max();
+        for bucket in &self.histogram {
+            let range = if bucket.min == bucket.max {
+                bucket.min.to_string()
+            } else {
+                format!("{}-{}", bucket.min, bucket.max)
+            };
+            // Any pairs at all get at least a bit of a bar
+            let bar = (bucket.pairs * HISTOGRAM_WIDTH).div_ceil(most.unwrap_or(1).max(1));
+            write!(f, "\n{range:>21} {:>8} {}", bucket.pairs, "#".repeat(bar))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{calculate_distance, calculate_simularity, explain::Explanation, parse_aoc_input};
+
+    #[test]
+    fn adds_up_to_the_answers() {
+        let input = parse_aoc_input(include_str!("../input_small.txt")).unwrap();
+        let explanation = Explanation::new(&input, 2);
+
+        assert_eq!(explanation.distance, calculate_distance(&input));
+        assert_eq!(explanation.similarity, calculate_simularity(&input));
+
+        // 4 and 9 are furthest apart, followed by the first of two pairs 2 apart
+        let ranks: Vec<usize> = explanation.top_pairs.iter().map(|pair| pair.rank).collect();
+        assert_eq!(ranks, [6, 1]);
+        // 3 occurs three times in both lists
+        assert_eq!(explanation.top_ids[0].contribution, 27);
+        assert_eq!(explanation.top_ids[1].id, 4);
+    }
+
+    #[test]
+    fn histogram_counts_every_pair() {
+        let input = parse_aoc_input("0 0\n1 4\n20 200\n").unwrap();
+        let explanation = Explanation::new(&input, 5);
+
+        let pairs: Vec<(i64, usize)> = explanation
+            .histogram
+            .iter()
+            .map(|bucket| (bucket.min, bucket.pairs))
+            .collect();
+        assert_eq!(pairs, [(0, 1), (1, 1), (10, 0), (100, 1)]);
+    }
+
+    #[test]
+    fn json_has_every_section() {
+        let input = parse_aoc_input("5 5\n").unwrap();
+        let json: serde_json::Value =
+            serde_json::from_str(&Explanation::new(&input, 1).to_json()).unwrap();
+
+        assert_eq!(json["similarity"], 5);
+        assert_eq!(json["top_ids"][0]["occurances_second"], 1);
+        assert_eq!(json["histogram"][0]["pairs"], 1);
+    }
+}
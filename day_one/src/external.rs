@@ -0,0 +1,341 @@
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, BufWriter, Read, Write},
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use aoc_common::error::SyntaxError;
+
+use crate::parser::parse_line;
+
+/// A location in memory and in a run file: the ID shifted left by one, with the list (0 or 1)
+/// in the lowest bit. Sorting these sorts by ID.
+type Point = i64;
+
+const POINT_SIZE: usize = size_of::<Point>();
+
+/// The least memory to work with, less would only make a lot of tiny runs.
+pub const MIN_MEMORY_BUDGET: usize = 64 * 1024;
+
+/// The most runs that are merged at once, even when the memory budget has room for more. More
+/// runs are merged into fewer, longer runs first.
+const MAX_MERGE_WIDTH: usize = 64;
+
+/// The smallest buffer for reading a run while merging. Only as many runs are merged at once as
+/// the memory budget has these buffers for.
+const MIN_READ_BUFFER: usize = 4096;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ExternalError {
+    #[error("invalid input, {0}")]
+    Parse(SyntaxError),
+    #[error("{0}")]
+    Io(#[from] io::Error),
+}
+
+/// The answers of both parts for the first two lists, which can be larger than the answers
+/// for lists that fit in memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExternalAnswers {
+    pub distance: i128,
+    pub similarity: i128,
+    /// The number of locations in each list
+    pub locations: u64,
+}
+
+/// Solves both parts for lists that do not fit in memory, using about `memory_budget` bytes
+/// (at least [`MIN_MEMORY_BUDGET`]). The input is read line by line and the locations are
+/// sorted in runs that fit the budget, which are written to files in `temp_directory`. Merging
+/// the runs gives the locations of both lists in order, from which the distance and similarity
+/// are found in a single pass. Only the first two lists are compared when there are more.
+pub fn solve(
+    input: impl BufRead,
+    memory_budget: usize,
+    temp_directory: &Path,
+) -> Result<ExternalAnswers, ExternalError> {
+    let memory_budget = memory_budget.max(MIN_MEMORY_BUDGET);
+    solve_in_runs(
+        input,
+        memory_budget / POINT_SIZE,
+        merge_width(memory_budget),
+        temp_directory,
+    )
+}
+
+/// How many runs fit in the memory budget while merging, each with a read buffer of at least
+/// [`MIN_READ_BUFFER`] bytes.
+fn merge_width(memory_budget: usize) -> usize {
+    (memory_budget / MIN_READ_BUFFER).clamp(2, MAX_MERGE_WIDTH)
+}
+
+fn solve_in_runs(
+    input: impl BufRead,
+    points_per_run: usize,
+    merge_width: usize,
+    temp_directory: &Path,
+) -> Result<ExternalAnswers, ExternalError> {
+    let mut runs = Runs::create(temp_directory)?;
+    let mut points = Vec::with_capacity(points_per_run);
+    let mut columns = None;
+
+    for (index, line) in input.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let numbers = parse_line(&line, index + 1, columns).map_err(ExternalError::Parse)?;
+        columns = Some(numbers.len());
+
+        if points.len() + 2 > points_per_run {
+            runs.write(&mut points)?;
+        }
+        points.push(point(numbers[0], 0));
+        points.push(point(numbers[1], 1));
+    }
+
+    if runs.paths.is_empty() {
+        // Everything fit in memory after all
+        points.sort_unstable();
+        return Ok(measure(points.into_iter().map(Ok))?);
+    }
+    runs.write(&mut points)?;
+    drop(points);
+
+    // The buffers of all runs share the memory budget
+    let read_buffer = (points_per_run * POINT_SIZE / merge_width).max(MIN_READ_BUFFER);
+    while runs.paths.len() > merge_width {
+        let merged_paths = runs.paths.split_off(0);
+        for group in merged_paths.chunks(merge_width) {
+            let merged = Merge::open(group, read_buffer)?;
+            runs.write_sorted(merged)?;
+            for path in group {
+                fs::remove_file(path)?;
+            }
+        }
+    }
+
+    Ok(measure(Merge::open(&runs.paths, read_buffer)?)?)
+}
+
+fn point(id: i32, list: i64) -> Point {
+    ((id as i64) << 1) | list
+}
+
+/// Walks through the locations of both lists in order. The similarity adds up every ID times
+/// how often it occurs in both lists. The distance is the area between zero and the difference
+/// in how many locations of each list have been seen (which equals the distance of pairing the
+/// smallest locations of both lists, then the next smallest, and so on).
+fn measure(points: impl Iterator<Item = io::Result<Point>>) -> io::Result<ExternalAnswers> {
+    let mut answers = ExternalAnswers {
+        distance: 0,
+        similarity: 0,
+        locations: 0,
+    };
+    let mut difference: i128 = 0;
+    let mut previous_id = None;
+    // How often the current ID occurs in each list
+    let mut occurances = [0; 2];
+
+    for point in points {
+        let point = point?;
+        let (id, list) = ((point >> 1) as i128, (point & 1) as usize);
+
+        if let Some(previous_id) = previous_id.filter(|previous_id| *previous_id != id) {
+            answers.similarity += previous_id * occurances[0] * occurances[1];
+            answers.distance += difference.abs() * (id - previous_id);
+            occurances = [0; 2];
+        }
+
+        occurances[list] += 1;
+        difference += if list == 0 { 1 } else { -1 };
+        answers.locations += (list == 0) as u64;
+        previous_id = Some(id);
+    }
+
+    if let Some(previous_id) = previous_id {
+        answers.similarity += previous_id * occurances[0] * occurances[1];
+    }
+
+    Ok(answers)
+}
+
+/// Sorted runs of points in a temporary directory, which is removed when done.
+struct Runs {
+    directory: PathBuf,
+    paths: Vec<PathBuf>,
+    created: usize,
+}
+
+impl Runs {
+    fn create(temp_directory: &Path) -> io::Result<Self> {
+        // Solving more than once at the same time (like in tests) needs separate directories
+        static SOLVES: AtomicUsize = AtomicUsize::new(0);
+
+        let directory = temp_directory.join(format!(
+            "aoc_day_one_{}_{}",
+            std::process::id(),
+            SOLVES.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::create_dir_all(&directory)?;
+
+        Ok(Self {
+            directory,
+            paths: vec![],
+            created: 0,
+        })
+    }
+
+    /// Sorts the points into a new run, leaving the vector empty.
+    fn write(&mut self, points: &mut Vec<Point>) -> io::Result<()> {
+        points.sort_unstable();
+        self.write_sorted(points.drain(..).map(Ok))
+    }
+
+    fn write_sorted(&mut self, points: impl Iterator<Item = io::Result<Point>>) -> io::Result<()> {
+        let path = self.directory.join(format!("run_{}", self.created));
+        self.created += 1;
+
+        let mut file = BufWriter::new(File::create(&path)?);
+        for point in points {
+            file.write_all(&point?.to_le_bytes())?;
+        }
+        file.flush()?;
+
+        self.paths.push(path);
+        Ok(())
+    }
+}
+
+impl Drop for Runs {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.directory);
+    }
+}
+
+/// The points of several runs, in order.
+struct Merge {
+    runs: Vec<BufReader<File>>,
+    /// The next point of every run that has not been used up
+    next: BinaryHeap<Reverse<(Point, usize)>>,
+}
+
+impl Merge {
+    fn open(paths: &[PathBuf], read_buffer: usize) -> io::Result<Self> {
+        let mut merge = Self {
+            runs: paths
+                .iter()
+                .map(|path| Ok(BufReader::with_capacity(read_buffer, File::open(path)?)))
+                .collect::<io::Result<_>>()?,
+            next: BinaryHeap::with_capacity(paths.len()),
+        };
+
+        for run in 0..merge.runs.len() {
+            merge.read(run)?;
+        }
+        Ok(merge)
+    }
+
+    fn read(&mut self, run: usize) -> io::Result<()> {
+        let mut bytes = [0; POINT_SIZE];
+        match self.runs[run].read_exact(&mut bytes) {
+            Ok(()) => {
+                self.next.push(Reverse((Point::from_le_bytes(bytes), run)));
+                Ok(())
+            }
+            Err(error) if error.kind() == io::ErrorKind::UnexpectedEof => Ok(()),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+impl Iterator for Merge {
+    type Item = io::Result<Point>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Reverse((point, run)) = self.next.pop()?;
+        Some(self.read(run).map(|()| point))
+    }
+}
+
+/// Parses a memory budget in bytes, with an optional `K`, `M` or `G` suffix (powers of 1024).
+pub fn parse_memory_budget(budget: &str) -> Option<usize> {
+    let (number, unit) = match budget.trim().to_ascii_uppercase() {
+        budget if budget.ends_with('K') => (budget[..budget.len() - 1].to_owned(), 1 << 10),
+        budget if budget.ends_with('M') => (budget[..budget.len() - 1].to_owned(), 1 << 20),
+        budget if budget.ends_with('G') => (budget[..budget.len() - 1].to_owned(), 1 << 30),
+        budget => (budget, 1),
+    };
+
+    number.parse::<usize>().ok()?.checked_mul(unit)
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use aoc_common::{random::Rng, solution::Generate};
+
+    use crate::{
+        calculate_distance, calculate_simularity,
+        external::{
+            merge_width, parse_memory_budget, solve, solve_in_runs, ExternalError,
+            MIN_MEMORY_BUDGET, MIN_READ_BUFFER,
+        },
+        parse_aoc_input, DayOne,
+    };
+
+    #[test]
+    fn many_small_runs_give_the_same_answers() {
+        let input = DayOne::generate(&mut Rng::new(4), 500);
+        let parsed = parse_aoc_input(&input).unwrap();
+
+        // 20 points per run makes 50 runs, merged three at a time in several rounds
+        let answers = solve_in_runs(Cursor::new(&input), 20, 3, &std::env::temp_dir()).unwrap();
+
+        assert_eq!(answers.distance, calculate_distance(&parsed) as i128);
+        assert_eq!(answers.similarity, calculate_simularity(&parsed) as i128);
+        assert_eq!(answers.locations, 500);
+    }
+
+    #[test]
+    fn small_inputs_stay_in_memory() {
+        let answers = solve(
+            Cursor::new(include_str!("../input_small.txt")),
+            0,
+            &std::env::temp_dir(),
+        )
+        .unwrap();
+
+        assert_eq!((answers.distance, answers.similarity), (11, 31));
+    }
+
+    #[test]
+    fn errors_have_the_line_number() {
+        let error = solve(Cursor::new("1 2\n\n3 x\n"), 0, &std::env::temp_dir()).unwrap_err();
+
+        let ExternalError::Parse(error) = error else {
+            panic!("Invalid input should be a parse error");
+        };
+        assert_eq!(error.location.line, 3);
+    }
+
+    #[test]
+    fn read_buffers_fit_in_the_memory_budget() {
+        for budget in [MIN_MEMORY_BUDGET, 100_000, 1 << 20, 1 << 30] {
+            assert!(merge_width(budget) * MIN_READ_BUFFER <= budget, "{budget}");
+        }
+        assert_eq!(merge_width(MIN_MEMORY_BUDGET), 16);
+    }
+
+    #[test]
+    fn memory_budget_with_units() {
+        assert_eq!(parse_memory_budget("512"), Some(512));
+        assert_eq!(parse_memory_budget("64k"), Some(64 * 1024));
+        assert_eq!(parse_memory_budget("2G"), Some(2 << 30));
+        assert_eq!(parse_memory_budget("lots"), None);
+    }
+}
@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+
+use aoc_common::{
+    error::SyntaxError,
+    input::InputFiles,
+    random::Rng,
+    solution::{Day, Generate, Solution},
+};
+
+pub use explain::Explanation;
+pub use matrix::PairwiseMatrix;
+pub use parser::parse_aoc_input;
+pub use store::LocationStore;
+
+pub mod explain;
+pub mod external;
+pub mod matrix;
+pub mod parser;
+pub mod store;
+
+pub const INPUT_FILES: InputFiles = InputFiles::new(env!("CARGO_MANIFEST_DIR"), "input_small.txt");
+
+pub const DAY: Day = Day::new::<DayOne>(1, &INPUT_FILES).with_generator::<DayOne>();
+
+pub struct DayOne;
+
+impl Solution for DayOne {
+    type Input = InputNumbers;
+    type PartOne = PairwiseMatrix;
+    type PartTwo = PairwiseMatrix;
+    type Error = SyntaxError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse_aoc_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne, Self::Error> {
+        Ok(distance_matrix(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo, Self::Error> {
+        Ok(similarity_matrix(input))
+    }
+}
+
+/// `size` lines with a number for both lists. Numbers are drawn from a pool of about `size`
+/// numbers, so the second list repeats some of the first.
+impl Generate for DayOne {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let pool: Vec<i64> = (0..size.max(2)).map(|_| rng.range(10000..100000)).collect();
+
+        (0..size)
+            .map(|_| format!("{}   {}\n", rng.pick(&pool), rng.pick(&pool)))
+            .collect()
+    }
+}
+
+/// The location lists of all teams, one list per column of the input, each sorted.
+pub struct InputNumbers {
+    lists: Vec<Vec<i32>>,
+    /// How often every number occurs, for every list
+    counted_occurances: Vec<HashMap<i32, usize>>,
+}
+
+impl InputNumbers {
+    fn new(mut lists: Vec<Vec<i32>>) -> Self {
+        let counted_occurances = lists
+            .iter()
+            .map(|list| {
+                let mut occurances = HashMap::new();
+                for number in list {
+                    *occurances.entry(*number).or_insert(0) += 1;
+                }
+                occurances
+            })
+            .collect();
+
+        for list in &mut lists {
+            list.sort();
+        }
+
+        Self {
+            lists,
+            counted_occurances,
+        }
+    }
+
+    /// The number of lists, at least two.
+    pub fn lists(&self) -> usize {
+        self.lists.len()
+    }
+}
+
+/// The distance between the first two lists, the answer of part one.
+pub fn calculate_distance(input: &InputNumbers) -> i64 {
+    distance_between(input, 0, 1)
+}
+
+/// The similarity of the first list to the second, the answer of part two.
+pub fn calculate_simularity(input: &InputNumbers) -> i64 {
+    similarity_between(input, 0, 1)
+}
+
+/// The sum of the differences between the smallest numbers of both lists, then the next
+/// smallest, and so on. Two locations can be further apart than fits in an `i32`.
+pub fn distance_between(input: &InputNumbers, first: usize, second: usize) -> i64 {
+    let total_difference: i64 = input.lists[first]
+        .iter()
+        .zip(&input.lists[second])
+        .map(|(a, b)| i64::abs(*a as i64 - *b as i64))
+        .sum();
+
+    total_difference
+}
+
+/// Every number of the first list multiplied by how often it occurs in the second list, summed.
+/// Unlike the distance, this depends on the order of the lists.
+pub fn similarity_between(input: &InputNumbers, first: usize, second: usize) -> i64 {
+    let simularity_score: i64 = input.lists[first]
+        .iter()
+        .filter_map(|list_one_number| {
+            input.counted_occurances[second]
+                .get(list_one_number)
+                .map(|occurances| *list_one_number as i64 * *occurances as i64)
+        })
+        .sum();
+
+    simularity_score
+}
+
+pub fn distance_matrix(input: &InputNumbers) -> PairwiseMatrix {
+    PairwiseMatrix::of(input.lists(), |first, second| {
+        distance_between(input, first, second)
+    })
+}
+
+pub fn similarity_matrix(input: &InputNumbers) -> PairwiseMatrix {
+    PairwiseMatrix::of(input.lists(), |first, second| {
+        similarity_between(input, first, second)
+    })
+}
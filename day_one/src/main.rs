@@ -1,83 +1,238 @@
-use std::collections::HashMap;
-
-// Using Winnow parser in the future?
-
-struct InputNumbers {
-    list_one_numbers: Vec<i32>,
-    list_two_numbers: Vec<i32>,
-    list_two_counted_occurances: HashMap<i32, usize>,
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader},
+    process::ExitCode,
+};
+
+use aoc_common::{
+    cli::{self, parse_options, Options, OPTIONS_USAGE},
+    input::InputSource,
+    solution::{Answers, Solution},
+};
+use day_one::{
+    external::{self, parse_memory_budget},
+    DayOne, Explanation, DAY, INPUT_FILES,
+};
+
+const DAY_ONE_USAGE: &str = "[--memory <bytes[K|M|G]> | --explain [--top <n>]]";
+
+/// The number of pairs and IDs to explain, unless `--top` says otherwise.
+const DEFAULT_TOP: usize = 10;
+
+#[derive(Debug, PartialEq, Eq)]
+struct Args {
+    options: Options,
+    mode: Mode,
 }
 
-fn main() {
-    let start = std::time::Instant::now();
-
-    let parsed_input = parse_aoc_input();
-
-    // First star
-    let distance_sum = calculate_distance(&parsed_input);
-
-    // Second star
-    let simularity_score = calculate_simularity(&parsed_input);
+/// What to do with the input, besides solving it like every other day.
+#[derive(Debug, PartialEq, Eq)]
+enum Mode {
+    Solve,
+    /// Solve lists too big for memory by sorting them on disk, using about this many bytes
+    External {
+        memory_budget: usize,
+    },
+    /// Explain where the answers come from, listing this many pairs and IDs
+    Explain {
+        top: usize,
+    },
+}
 
-    // Record the amount of time it took to run the program
-    println!("Time elapsed: {:?}", start.elapsed());
+fn main() -> ExitCode {
+    let usage = format!("Usage: day_one {OPTIONS_USAGE} {DAY_ONE_USAGE}");
+
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("{message}");
+            eprintln!("{usage}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match args.mode {
+        // The common options are parsed again, but there is nothing day one specific left
+        Mode::Solve => cli::main(&DAY),
+        Mode::External { memory_budget } => solve_external(&args.options, memory_budget),
+        Mode::Explain { top } => explain(&args.options, top),
+    }
+}
 
-    println!("Calculated distance: {distance_sum}");
-    println!("Similarity value: {simularity_score}");
+fn parse_args(args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let (options, other_args) = parse_options(args)?;
+    let mut other_args = other_args.into_iter();
+    let (mut memory_budget, mut explain, mut top) = (None, false, None);
+
+    while let Some(arg) = other_args.next() {
+        match arg.as_str() {
+            "--memory" => match other_args.next().as_deref().and_then(parse_memory_budget) {
+                Some(budget) => memory_budget = Some(budget),
+                None => return Err("--memory needs a number of bytes, such as 512M".to_owned()),
+            },
+            "--explain" => explain = true,
+            "--top" => match other_args.next().map(|top| top.parse::<usize>()) {
+                Some(Ok(entries)) => top = Some(entries),
+                _ => return Err("--top needs a number of entries".to_owned()),
+            },
+            other => return Err(format!("Unexpected argument '{other}'")),
+        }
+    }
+
+    let mode = match (memory_budget, explain) {
+        (Some(_), true) => return Err("--memory and --explain can't be used together".to_owned()),
+        (Some(memory_budget), false) => {
+            // Streaming only reads an input, anything else needs it in memory after all
+            let only_input = Options {
+                input: options.input.clone(),
+                ..Options::default()
+            };
+            if options != only_input {
+                return Err("--memory can only be combined with --input".to_owned());
+            }
+            Mode::External { memory_budget }
+        }
+        (None, true) => {
+            // Explaining only reads an input (which can be generated) and how to print it
+            let explainable = Options {
+                input: options.input.clone(),
+                generate: options.generate,
+                seed: options.seed,
+                json: options.json,
+                ..Options::default()
+            };
+            if options != explainable {
+                return Err(
+                    "--explain can only be combined with --input, --generate, --seed and --json"
+                        .to_owned(),
+                );
+            }
+            Mode::Explain {
+                top: top.unwrap_or(DEFAULT_TOP),
+            }
+        }
+        (None, false) if top.is_some() => return Err("--top needs --explain".to_owned()),
+        (None, false) => Mode::Solve,
+    };
+
+    Ok(Args { options, mode })
 }
 
-fn parse_aoc_input() -> InputNumbers {
-    let mut parsed_input = include_str!("../input.txt").lines().fold(
-        InputNumbers {
-            list_one_numbers: vec![],
-            list_two_numbers: vec![],
-            list_two_counted_occurances: HashMap::new(),
+fn solve_external(options: &Options, memory_budget: usize) -> ExitCode {
+    // Without an input, the puzzle input or the example is streamed (stdin is only read when
+    // asked for, as checking whether anything is piped would read it all)
+    let reader: Box<dyn BufRead> = match options.input.clone().unwrap_or(InputSource::Auto) {
+        InputSource::Stdin => Box::new(io::stdin().lock()),
+        InputSource::File(path) => match File::open(&path) {
+            Ok(file) => Box::new(BufReader::new(file)),
+            Err(error) => return fail(format!("unable to read input: {error}")),
         },
-        |mut parsed, line| {
-            let list_one_number = line[..5].parse::<i32>().unwrap();
-            parsed.list_one_numbers.push(list_one_number);
-
-            let list_two_number = line[8..].parse::<i32>().unwrap();
-            parsed.list_two_numbers.push(list_two_number);
-
-            parsed
-                .list_two_counted_occurances
-                .entry(list_two_number)
-                .and_modify(|count| *count += 1)
-                .or_insert(1);
-
-            parsed
+        InputSource::Auto => match File::open(INPUT_FILES.default_input()) {
+            Ok(file) => Box::new(BufReader::new(file)),
+            Err(error) => return fail(format!("unable to read input: {error}")),
         },
-    );
-
-    parsed_input.list_one_numbers.sort();
-    parsed_input.list_two_numbers.sort();
-
-    parsed_input
+    };
+
+    match external::solve(reader, memory_budget, &std::env::temp_dir()) {
+        Ok(answers) => {
+            cli::print_answers(
+                DAY.number,
+                &Answers {
+                    part_one: Some(answers.distance.to_string()),
+                    part_two: Some(answers.similarity.to_string()),
+                },
+            );
+            ExitCode::SUCCESS
+        }
+        Err(error) => fail(error.to_string()),
+    }
 }
 
-fn calculate_distance(input: &InputNumbers) -> i32 {
-    let total_difference: i32 = input
-        .list_one_numbers
-        .iter()
-        .zip(&input.list_two_numbers)
-        .map(|(a, b)| i32::abs(a - b))
-        .sum();
+fn explain(options: &Options, top: usize) -> ExitCode {
+    let source = options.input.clone().unwrap_or(InputSource::Auto);
+    let input = match cli::read_input(&DAY, options, &source) {
+        Ok(input) => input,
+        Err(message) => return fail(message),
+    };
+    let parsed = match DayOne::parse(&input.text) {
+        Ok(parsed) => parsed,
+        Err(error) => return fail(format!("invalid input, {}", error.report(&input.text))),
+    };
+
+    let explanation = Explanation::new(&parsed, top);
+    if options.json {
+        println!("{}", explanation.to_json());
+    } else {
+        println!("{explanation}");
+    }
+    ExitCode::SUCCESS
+}
 
-    total_difference
+fn fail(message: String) -> ExitCode {
+    eprintln!("Day {:>2}, {message}", DAY.number);
+    ExitCode::FAILURE
 }
 
-fn calculate_simularity(input: &InputNumbers) -> i32 {
-    let simularity_score: i32 = input
-        .list_one_numbers
-        .iter()
-        .filter_map(|list_one_number| {
-            input
-                .list_two_counted_occurances
-                .get(list_one_number)
-                .map(|occurances| list_one_number * *occurances as i32)
-        })
-        .sum();
-
-    simularity_score
+#[cfg(test)]
+mod test {
+    use aoc_common::{cli::Options, input::InputSource};
+
+    use crate::{parse_args, Args, Mode};
+
+    fn args(args: &[&str]) -> Result<Args, String> {
+        parse_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn memory_with_an_input() {
+        let expected = Args {
+            options: Options {
+                input: Some(InputSource::Stdin),
+                ..Options::default()
+            },
+            mode: Mode::External {
+                memory_budget: 2 << 20,
+            },
+        };
+
+        assert_eq!(args(&["--memory", "2M", "-i", "-"]), Ok(expected));
+        assert!(args(&["--memory", "2M", "--part", "1"]).is_err());
+    }
+
+    #[test]
+    fn an_input_named_like_an_option_is_just_an_input() {
+        let parsed = args(&["--input", "--memory"]).unwrap();
+
+        assert_eq!(parsed.mode, Mode::Solve);
+        assert_eq!(
+            parsed.options.input,
+            Some(InputSource::from_arg("--memory"))
+        );
+        assert!(args(&["--input", "--memory", "--memory"]).is_err());
+    }
+
+    #[test]
+    fn explain_uses_the_common_options() {
+        let parsed = args(&["--explain", "--json", "--generate", "20", "--top", "3"]).unwrap();
+
+        assert_eq!(parsed.mode, Mode::Explain { top: 3 });
+        assert!(parsed.options.json);
+        assert!(args(&["--top", "3"]).is_err());
+    }
+
+    #[test]
+    fn explain_rejects_options_it_would_ignore() {
+        for option in [
+            &["--part", "1"][..],
+            &["--time"],
+            &["--batch", "inputs"],
+            &["--check"],
+        ] {
+            let mut arguments = vec!["--explain"];
+            arguments.extend(option);
+
+            assert!(args(&arguments).is_err(), "{option:?}");
+        }
+        assert!(args(&["--explain", "--input", "-", "--seed", "3"]).is_ok());
+    }
 }
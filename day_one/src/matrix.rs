@@ -0,0 +1,78 @@
+use std::fmt::{self, Display};
+
+/// A metric (such as the distance) for every pair of lists, where the row is the first list of
+/// the pair and the column the second.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PairwiseMatrix {
+    values: Vec<Vec<i64>>,
+}
+
+impl PairwiseMatrix {
+    pub fn of(lists: usize, metric: impl Fn(usize, usize) -> i64) -> Self {
+        Self {
+            values: (0..lists)
+                .map(|first| (0..lists).map(|second| metric(first, second)).collect())
+                .collect(),
+        }
+    }
+
+    pub fn get(&self, first: usize, second: usize) -> i64 {
+        self.values[first][second]
+    }
+
+    pub fn lists(&self) -> usize {
+        self.values.len()
+    }
+
+    /// The pair of different lists with the lowest value, such as the two lists that agree most
+    /// by distance. `None` when there are less than two lists.
+    pub fn lowest_pair(&self) -> Option<(usize, usize)> {
+        (0..self.lists())
+            .flat_map(|first| (0..self.lists()).map(move |second| (first, second)))
+            .filter(|(first, second)| first != second)
+            .min_by_key(|&(first, second)| self.get(first, second))
+    }
+
+    /// The pair of different lists with the highest value, such as the two lists that agree most
+    /// by similarity. `None` when there are less than two lists.
+    pub fn highest_pair(&self) -> Option<(usize, usize)> {
+        (0..self.lists())
+            .flat_map(|first| (0..self.lists()).map(move |second| (first, second)))
+            .filter(|(first, second)| first != second)
+            .max_by_key(|&(first, second)| self.get(first, second))
+    }
+}
+
+/// Two lists only have one pair that matters, so they show as that single value (the answer of
+/// the puzzle). More lists show as a table with a row and column per list, numbered from 1,
+/// starting on a new line so it does not run on from a label in front of it.
+impl Display for PairwiseMatrix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.lists() == 2 {
+            return write!(f, "{}", self.get(0, 1));
+        }
+
+        let width = self
+            .values
+            .iter()
+            .flatten()
+            .map(|value| value.to_string().len())
+            .chain([format!("list {}", self.lists()).len()])
+            .max()
+            .unwrap_or_default();
+
+        write!(f, "\n{:width$}", "")?;
+        for list in 1..=self.lists() {
+            write!(f, "  {:>width$}", format!("list {list}"))?;
+        }
+
+        for (list, row) in self.values.iter().enumerate() {
+            write!(f, "\n{:<width$}", format!("list {}", list + 1))?;
+            for value in row {
+                write!(f, "  {value:>width$}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,148 @@
+use aoc_common::error::{Location, SyntaxError};
+use aoc_parse::{line_end, list, parse_all, signed};
+use winnow::{
+    ascii::{line_ending, space0, space1},
+    combinator::{delimited, fail, not, repeat, terminated},
+    error::{ContextError, StrContext, StrContextValue},
+    stream::Stream,
+    Parser, Result,
+};
+
+use crate::InputNumbers;
+
+/// Parses the location lists, a column per list with a line for every location. There can be
+/// any number of lists, as long as there are at least two and every line has a number for each
+/// of them. The numbers can have any width and sign, separated by any amount of spaces or tabs.
+/// Blank lines are skipped.
+pub fn parse_aoc_input(input: &str) -> std::result::Result<InputNumbers, SyntaxError> {
+    let rows = parse_all(rows, input)?;
+    let columns = rows.first().map_or(2, Vec::len);
+
+    let lists = (0..columns)
+        .map(|column| rows.iter().map(|row| row[column]).collect())
+        .collect();
+
+    Ok(InputNumbers::new(lists))
+}
+
+/// Parses a single line of the input on its own, such as when streaming an input too big to
+/// read at once. `columns` is the number of lists found on the first line, if this is not the
+/// first line. Blank lines are not skipped here.
+pub fn parse_line(
+    line: &str,
+    line_number: usize,
+    columns: Option<usize>,
+) -> std::result::Result<Vec<i32>, SyntaxError> {
+    parse_all(row(columns), line).map_err(|error| {
+        let location = Location {
+            line: line_number,
+            ..error.location
+        };
+        SyntaxError { location, ..error }
+    })
+}
+
+fn rows(input: &mut &str) -> Result<Vec<Vec<i32>>> {
+    let mut rows: Vec<Vec<i32>> = vec![];
+
+    loop {
+        let () = repeat(0.., (space0, line_ending)).parse_next(input)?;
+        // Whatever whitespace is left is up to `parse_all`
+        if input.trim().is_empty() {
+            return Ok(rows);
+        }
+
+        // The first line decides how many lists there are
+        let columns = rows.first().map(Vec::len);
+        rows.push(row(columns).parse_next(input)?);
+    }
+}
+
+/// A number for every list, up to the end of the line.
+fn row<'i>(columns: Option<usize>) -> impl Parser<&'i str, Vec<i32>, ContextError> {
+    delimited(
+        space0,
+        move |input: &mut &'i str| {
+            let start = input.checkpoint();
+            // Whitespace at the end of the line does not separate numbers
+            let numbers = list(signed(), terminated(space1, not(line_end))).parse_next(input)?;
+
+            let expected = match columns {
+                Some(columns) if numbers.len() != columns => "as many numbers as on the first line",
+                None if numbers.len() < 2 => "a number for at least two lists",
+                _ => return Ok(numbers),
+            };
+            input.reset(&start);
+            fail.context(StrContext::Expected(StrContextValue::Description(expected)))
+                .parse_next(input)
+        },
+        (
+            space0,
+            line_end.context(StrContext::Expected(StrContextValue::Description(
+                "the end of the line",
+            ))),
+        ),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::error::Location;
+
+    use crate::parser::{parse_aoc_input, parse_line};
+
+    #[test]
+    fn numbers_of_any_width_and_sign() {
+        let parsed = parse_aoc_input("3   4\n-12\t7\n  100000 -5  \n").unwrap();
+
+        assert_eq!(parsed.lists, [vec![-12, 3, 100000], vec![-5, 4, 7]]);
+        assert_eq!(parsed.counted_occurances[1][&7], 1);
+    }
+
+    #[test]
+    fn blank_lines_are_skipped() {
+        let parsed = parse_aoc_input("\n1 2\r\n\r\n  \n3 2\n\n\n").unwrap();
+
+        assert_eq!(parsed.lists[0], [1, 3]);
+        assert_eq!(parsed.counted_occurances[1][&2], 2);
+    }
+
+    #[test]
+    fn any_number_of_lists() {
+        let parsed = parse_aoc_input("1 2 3 4\n5 6 7 8\n").unwrap();
+
+        assert_eq!(parsed.lists(), 4);
+        assert_eq!(parsed.lists[3], [4, 8]);
+    }
+
+    #[test]
+    fn single_lines_keep_their_line_number() {
+        assert_eq!(parse_line(" -3\t4 ", 7, Some(2)), Ok(vec![-3, 4]));
+
+        let error = parse_line("3 4 5", 7, Some(2)).unwrap_err();
+        assert_eq!(error.location, Location { line: 7, column: 1 });
+    }
+
+    #[test]
+    fn errors_point_at_the_line() {
+        let error = parse_aoc_input("1 2\n3 4 5\n").err().unwrap();
+
+        assert_eq!(error.location, Location { line: 2, column: 1 });
+        assert_eq!(error.expected, ["as many numbers as on the first line"]);
+
+        let error = parse_aoc_input("1\n3 4\n").err().unwrap();
+
+        assert_eq!(error.location, Location { line: 1, column: 1 });
+        assert_eq!(error.expected, ["a number for at least two lists"]);
+
+        let error = parse_aoc_input("1 2x\n").err().unwrap();
+
+        assert_eq!(error.location, Location { line: 1, column: 4 });
+        assert_eq!(error.expected, ["the end of the line"]);
+
+        let error = parse_aoc_input("1 x\n").err().unwrap();
+
+        assert_eq!(error.location, Location { line: 1, column: 3 });
+        assert_eq!(error.expected, ["a number"]);
+    }
+}
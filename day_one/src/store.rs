@@ -0,0 +1,387 @@
+use std::collections::HashMap;
+
+use crate::InputNumbers;
+
+/// The smallest number of points in a block, below this splitting up only adds overhead.
+const MIN_BLOCK_SIZE: usize = 16;
+
+/// One of the two lists of a [`LocationStore`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum List {
+    One,
+    Two,
+}
+
+impl List {
+    fn index(self) -> usize {
+        match self {
+            List::One => 0,
+            List::Two => 1,
+        }
+    }
+
+    /// How a location of this list moves the difference between the lists, see [`Points`].
+    fn delta(self) -> i8 {
+        match self {
+            List::One => 1,
+            List::Two => -1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edit {
+    Insert(List, i32),
+    Remove(List, i32),
+}
+
+/// Two location lists that change over time, keeping their distance and similarity up to date.
+/// An edit takes `O(√n log n)` time for the distance and `O(1)` for the similarity, instead of
+/// the `O(n log n)` of sorting and comparing the lists again.
+#[derive(Debug, Clone, Default)]
+pub struct LocationStore {
+    counted_occurances: [HashMap<i32, usize>; 2],
+    lengths: [usize; 2],
+    points: Points,
+    similarity: i64,
+}
+
+impl LocationStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A store with the first two lists of the input.
+    pub fn from_input(input: &InputNumbers) -> Self {
+        let lists = [&input.lists[0], &input.lists[1]];
+        let counted_occurances = [
+            input.counted_occurances[0].clone(),
+            input.counted_occurances[1].clone(),
+        ];
+
+        let similarity = lists[0]
+            .iter()
+            .map(|id| *id as i64 * counted_occurances[1].get(id).copied().unwrap_or(0) as i64)
+            .sum();
+
+        Self {
+            points: Points::from_lists(lists[0], lists[1]),
+            lengths: lists.map(Vec::len),
+            counted_occurances,
+            similarity,
+        }
+    }
+
+    pub fn insert(&mut self, list: List, id: i32) {
+        self.similarity += id as i64 * self.occurances(other(list), id) as i64;
+        *self.counted_occurances[list.index()].entry(id).or_insert(0) += 1;
+        self.lengths[list.index()] += 1;
+        self.points.insert(id, list.delta());
+    }
+
+    /// Removes one occurance of the ID from the list, returning whether it was there.
+    pub fn remove(&mut self, list: List, id: i32) -> bool {
+        let counted = &mut self.counted_occurances[list.index()];
+        match counted.get_mut(&id) {
+            None => return false,
+            Some(1) => {
+                counted.remove(&id);
+            }
+            Some(count) => *count -= 1,
+        }
+
+        self.similarity -= id as i64 * self.occurances(other(list), id) as i64;
+        self.lengths[list.index()] -= 1;
+        self.points.remove(id, list.delta());
+        true
+    }
+
+    /// Applies the edit, returning whether it changed the store.
+    pub fn apply(&mut self, edit: Edit) -> bool {
+        match edit {
+            Edit::Insert(list, id) => {
+                self.insert(list, id);
+                true
+            }
+            Edit::Remove(list, id) => self.remove(list, id),
+        }
+    }
+
+    pub fn len(&self, list: List) -> usize {
+        self.lengths[list.index()]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lengths == [0, 0]
+    }
+
+    /// The distance between the lists, `None` while they are not equally long (as every
+    /// location has to be paired with one of the other list).
+    pub fn distance(&self) -> Option<i64> {
+        (self.lengths[0] == self.lengths[1]).then(|| self.points.area())
+    }
+
+    pub fn similarity(&self) -> i64 {
+        self.similarity
+    }
+
+    fn occurances(&self, list: List, id: i32) -> usize {
+        self.counted_occurances[list.index()]
+            .get(&id)
+            .copied()
+            .unwrap_or(0)
+    }
+}
+
+fn other(list: List) -> List {
+    match list {
+        List::One => List::Two,
+        List::Two => List::One,
+    }
+}
+
+/// The locations of both lists as points on a line, where walking along the line the height
+/// goes up at every location of list one and down at every location of list two. When both
+/// lists are equally long, pairing their smallest locations, then the next smallest and so on
+/// gives a distance that is the area between the walk and zero. A single edit can shift every
+/// pair, but it only moves the walk after the edited point up or down by one.
+///
+/// The points are kept in blocks of about `√n` sorted points. Every block knows its area for
+/// any height it starts at, so the total area takes `O(√n log n)` time to add up.
+#[derive(Debug, Clone, Default)]
+struct Points {
+    blocks: Vec<Block>,
+    len: usize,
+    /// The size blocks are split into, chosen for the number of points at the last rebalance
+    block_size: usize,
+    balanced_len: usize,
+}
+
+impl Points {
+    fn from_lists(one: &[i32], two: &[i32]) -> Self {
+        let mut points = Self::default();
+        let mut all: Vec<(i32, i8)> = one
+            .iter()
+            .map(|id| (*id, 1))
+            .chain(two.iter().map(|id| (*id, -1)))
+            .collect();
+        all.sort_unstable();
+
+        points.rebalance(all);
+        points
+    }
+
+    fn insert(&mut self, value: i32, delta: i8) {
+        if self.blocks.is_empty() {
+            self.rebalance(vec![(value, delta)]);
+            return;
+        }
+        self.len += 1;
+
+        let index = self
+            .blocks
+            .partition_point(|block| block.values[0] <= value)
+            .saturating_sub(1);
+        let block = &mut self.blocks[index];
+        let position = block.values.partition_point(|other| *other <= value);
+        block.values.insert(position, value);
+        block.deltas.insert(position, delta);
+
+        if block.values.len() > 2 * self.block_size {
+            let second_half = block.split_off(block.values.len() / 2);
+            self.blocks.insert(index + 1, second_half);
+            self.blocks[index].rebuild();
+        } else {
+            block.rebuild();
+        }
+
+        self.rebalance_if_needed();
+    }
+
+    fn remove(&mut self, value: i32, delta: i8) {
+        // Equal values can continue into the next block
+        let first_candidate = self
+            .blocks
+            .partition_point(|block| *block.values.last().unwrap() < value);
+
+        for index in first_candidate..self.blocks.len() {
+            let block = &mut self.blocks[index];
+            let found = block
+                .values
+                .iter()
+                .zip(&block.deltas)
+                .position(|point| point == (&value, &delta));
+
+            if let Some(position) = found {
+                block.values.remove(position);
+                block.deltas.remove(position);
+                if block.values.is_empty() {
+                    self.blocks.remove(index);
+                } else {
+                    block.rebuild();
+                }
+
+                self.len -= 1;
+                self.rebalance_if_needed();
+                return;
+            }
+        }
+
+        unreachable!("The store only removes points it counted");
+    }
+
+    /// Blocks are split as they grow, but small blocks are only merged when the number of points
+    /// has doubled or halved since the last rebalance. That keeps every edit `O(√n)` amortized.
+    fn rebalance_if_needed(&mut self) {
+        if self.len > 2 * self.balanced_len || self.len < self.balanced_len / 2 {
+            let all = self
+                .blocks
+                .drain(..)
+                .flat_map(|block| block.values.into_iter().zip(block.deltas))
+                .collect();
+            self.rebalance(all);
+        }
+    }
+
+    fn rebalance(&mut self, sorted: Vec<(i32, i8)>) {
+        self.len = sorted.len();
+        self.balanced_len = self.len.max(MIN_BLOCK_SIZE);
+        self.block_size = (self.len as f64).sqrt().ceil().max(MIN_BLOCK_SIZE as f64) as usize;
+
+        self.blocks = sorted
+            .chunks(self.block_size)
+            .map(|chunk| {
+                let mut block = Block {
+                    values: chunk.iter().map(|(value, _)| *value).collect(),
+                    deltas: chunk.iter().map(|(_, delta)| *delta).collect(),
+                    ..Block::default()
+                };
+                block.rebuild();
+                block
+            })
+            .collect();
+    }
+
+    /// The area between the walk and zero, from the first point to the last.
+    fn area(&self) -> i64 {
+        let mut area = 0;
+        let mut height = 0;
+
+        for (index, block) in self.blocks.iter().enumerate() {
+            area += block.area(height);
+            height += block.total;
+
+            if let Some(next) = self.blocks.get(index + 1) {
+                let gap = next.values[0] as i64 - *block.values.last().unwrap() as i64;
+                area += height.abs() * gap;
+            }
+        }
+
+        area
+    }
+}
+
+/// Sorted points, and the stretches between them ordered by the height of the walk relative to
+/// the start of the block.
+#[derive(Debug, Clone, Default)]
+struct Block {
+    values: Vec<i32>,
+    deltas: Vec<i8>,
+    /// The height at the end of the block, relative to its start
+    total: i64,
+    heights: Vec<i64>,
+    /// Running sums (starting at zero) of the lengths of the stretches, in order of height
+    lengths: Vec<i64>,
+    /// Running sums of height times length
+    areas: Vec<i64>,
+}
+
+impl Block {
+    fn split_off(&mut self, at: usize) -> Self {
+        let mut second_half = Self {
+            values: self.values.split_off(at),
+            deltas: self.deltas.split_off(at),
+            ..Self::default()
+        };
+        second_half.rebuild();
+        second_half
+    }
+
+    fn rebuild(&mut self) {
+        let mut height = 0;
+        let mut stretches = Vec::with_capacity(self.values.len());
+
+        for (index, delta) in self.deltas.iter().enumerate() {
+            height += *delta as i64;
+            if let Some(next) = self.values.get(index + 1) {
+                stretches.push((height, *next as i64 - self.values[index] as i64));
+            }
+        }
+        stretches.sort_unstable();
+
+        self.total = height;
+        self.heights = stretches.iter().map(|(height, _)| *height).collect();
+        self.lengths = running_sums(stretches.iter().map(|(_, length)| *length));
+        self.areas = running_sums(stretches.iter().map(|(height, length)| height * length));
+    }
+
+    /// The area between the walk within this block and zero, when the block starts at `start`.
+    fn area(&self, start: i64) -> i64 {
+        let below = self.heights.partition_point(|height| start + height < 0);
+        let (length, area) = (*self.lengths.last().unwrap(), *self.areas.last().unwrap());
+        let (length_below, area_below) = (self.lengths[below], self.areas[below]);
+
+        let above = (area - area_below) + start * (length - length_below);
+        let below = area_below + start * length_below;
+        above - below
+    }
+}
+
+fn running_sums(values: impl Iterator<Item = i64>) -> Vec<i64> {
+    let mut sums = vec![0];
+    for value in values {
+        sums.push(sums.last().unwrap() + value);
+    }
+    sums
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        parse_aoc_input,
+        store::{Edit, List, LocationStore},
+    };
+
+    #[test]
+    fn starts_from_the_input() {
+        let input = parse_aoc_input(include_str!("../input_small.txt")).unwrap();
+        let store = LocationStore::from_input(&input);
+
+        assert_eq!(store.distance(), Some(11));
+        assert_eq!(store.similarity(), 31);
+    }
+
+    #[test]
+    fn distance_needs_lists_of_the_same_length() {
+        let mut store = LocationStore::new();
+        assert_eq!(store.distance(), Some(0));
+
+        store.insert(List::One, 3);
+        assert_eq!(store.distance(), None);
+
+        store.insert(List::Two, 7);
+        assert_eq!(store.distance(), Some(4));
+    }
+
+    #[test]
+    fn removing_a_missing_id_changes_nothing() {
+        let mut store = LocationStore::new();
+        store.insert(List::One, 5);
+        store.insert(List::Two, 5);
+
+        assert!(!store.apply(Edit::Remove(List::Two, 6)));
+        assert!(store.apply(Edit::Remove(List::Two, 5)));
+        assert_eq!((store.len(List::One), store.len(List::Two)), (1, 0));
+        assert_eq!(store.similarity(), 0);
+    }
+}
@@ -0,0 +1,68 @@
+use day_one::{
+    calculate_distance, calculate_simularity, distance_matrix, parse_aoc_input, similarity_matrix,
+};
+
+const THREE_LISTS: &str = "1 3 1\n2 2 1\n3 1 5\n";
+
+#[test]
+fn distances_between_every_pair_of_lists() {
+    let matrix = distance_matrix(&parse_aoc_input(THREE_LISTS).unwrap());
+
+    assert_eq!(matrix.lists(), 3);
+    assert_eq!(
+        (matrix.get(0, 1), matrix.get(0, 2), matrix.get(2, 1)),
+        (0, 3, 3)
+    );
+    assert_eq!(matrix.get(2, 2), 0);
+    assert_eq!(matrix.lowest_pair(), Some((0, 1)));
+}
+
+#[test]
+fn similarity_depends_on_the_order_of_the_lists() {
+    let matrix = similarity_matrix(&parse_aoc_input(THREE_LISTS).unwrap());
+
+    assert_eq!(matrix.get(0, 1), 6);
+    assert_eq!(matrix.get(0, 2), 2);
+    assert_eq!(matrix.get(2, 2), 9);
+    assert_eq!(matrix.get(2, 0), 2);
+
+    let (first, second) = matrix.highest_pair().unwrap();
+    assert_eq!(matrix.get(first, second), 6);
+}
+
+#[test]
+fn two_lists_show_as_the_answer() {
+    let input = parse_aoc_input(include_str!("../input_small.txt")).unwrap();
+
+    assert_eq!(distance_matrix(&input).to_string(), "11");
+    assert_eq!(similarity_matrix(&input).to_string(), "31");
+}
+
+#[test]
+fn more_lists_show_as_a_table() {
+    let matrix = distance_matrix(&parse_aoc_input(THREE_LISTS).unwrap());
+
+    assert_eq!(
+        matrix.to_string(),
+        "
+        list 1  list 2  list 3
+list 1       0       0       3
+list 2       0       0       3
+list 3       3       3       0"
+    );
+}
+
+#[test]
+fn locations_far_apart_do_not_overflow() {
+    let input = parse_aoc_input("2000000000   -2000000000\n").unwrap();
+
+    assert_eq!(calculate_distance(&input), 4_000_000_000);
+}
+
+#[test]
+fn large_repeated_ids_do_not_overflow() {
+    let input = parse_aoc_input("2000000000   2000000000\n2000000000   2000000000\n").unwrap();
+
+    assert_eq!(calculate_simularity(&input), 8_000_000_000);
+    assert_eq!(similarity_matrix(&input).get(1, 0), 8_000_000_000);
+}
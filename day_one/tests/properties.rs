@@ -0,0 +1,99 @@
+use aoc_common::{random::Rng, solution::Generate};
+use day_one::{
+    calculate_distance, calculate_simularity, parse_aoc_input,
+    store::{Edit, List},
+    DayOne, LocationStore,
+};
+
+const CASES: u64 = 50;
+
+fn columns(input: &str) -> (Vec<i32>, Vec<i32>) {
+    input
+        .lines()
+        .map(|line| {
+            let (left, right) = line.split_once("   ").unwrap();
+            (left.parse::<i32>().unwrap(), right.parse::<i32>().unwrap())
+        })
+        .unzip()
+}
+
+/// Pairs up the smallest numbers left in both lists, one pair at a time.
+fn reference_distance(mut left: Vec<i32>, mut right: Vec<i32>) -> i64 {
+    let mut distance = 0;
+
+    while !left.is_empty() {
+        let take_smallest = |list: &mut Vec<i32>| {
+            let (index, _) = list.iter().enumerate().min_by_key(|(_, n)| **n).unwrap();
+            list.swap_remove(index)
+        };
+        distance += (take_smallest(&mut left) as i64 - take_smallest(&mut right) as i64).abs();
+    }
+
+    distance
+}
+
+fn reference_similarity(left: &[i32], right: &[i32]) -> i64 {
+    left.iter()
+        .map(|number| *number as i64 * right.iter().filter(|other| *other == number).count() as i64)
+        .sum()
+}
+
+#[test]
+fn solutions_match_the_reference() {
+    for seed in 0..CASES {
+        let input = DayOne::generate(&mut Rng::new(seed), 1 + seed as usize * 4);
+        let parsed = parse_aoc_input(&input).unwrap();
+        let (left, right) = columns(&input);
+
+        assert_eq!(
+            calculate_simularity(&parsed),
+            reference_similarity(&left, &right),
+            "seed {seed}"
+        );
+        assert_eq!(
+            calculate_distance(&parsed),
+            reference_distance(left, right),
+            "seed {seed}"
+        );
+    }
+}
+
+#[test]
+fn store_matches_the_reference_after_every_edit() {
+    for seed in 0..CASES / 5 {
+        let mut rng = Rng::new(seed);
+        let input = DayOne::generate(&mut rng, seed as usize * 10);
+        let (mut left, mut right) = columns(&input);
+        let mut store = LocationStore::from_input(&parse_aoc_input(&input).unwrap());
+
+        // Few different IDs, so removals usually find something and duplicates are common
+        for _ in 0..400 {
+            let id = rng.range(-20..20) as i32;
+            let (list, numbers) = if rng.chance(0.5) {
+                (List::One, &mut left)
+            } else {
+                (List::Two, &mut right)
+            };
+
+            if rng.chance(0.55) {
+                store.apply(Edit::Insert(list, id));
+                numbers.push(id);
+            } else {
+                let position = numbers.iter().position(|number| *number == id);
+                assert_eq!(store.apply(Edit::Remove(list, id)), position.is_some());
+                if let Some(position) = position {
+                    numbers.swap_remove(position);
+                }
+            }
+
+            let expected_distance = (left.len() == right.len())
+                .then(|| reference_distance(left.clone(), right.clone()));
+            assert_eq!(store.distance(), expected_distance, "seed {seed}");
+            assert_eq!(
+                store.similarity(),
+                reference_similarity(&left, &right),
+                "seed {seed}"
+            );
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use aoc_common::{
+    error::SyntaxError,
+    input::InputFiles,
+    random::Rng,
+    solution::{Day, Generate, Solution},
+};
+use aoc_parse::{lines, parse_all, unsigned, unsigned_list};
+use operator::Operator;
+use operator::OperatorList;
+use rayon::prelude::*;
+use std::sync::LazyLock;
+use winnow::ascii::space1;
+use winnow::combinator::{fail, separated_pair};
+use winnow::error::{StrContext, StrContextValue};
+use winnow::stream::Stream;
+use winnow::Parser;
+use winnow::Result;
+
+mod operator;
+
+/// The most numbers an equation can have. Every combination of operators between them is tried,
+/// which for part two is `3^12` combinations (the puzzle input has at most 12 numbers).
+pub const MAX_PARTS: usize = 13;
+
+static TOO_MANY_PARTS: LazyLock<String> = LazyLock::new(|| format!("at most {MAX_PARTS} numbers"));
+
+pub const INPUT_FILES: InputFiles = InputFiles::new(env!("CARGO_MANIFEST_DIR"), "input_small.txt");
+
+pub const DAY: Day = Day::new::<DaySeven>(7, &INPUT_FILES).with_generator::<DaySeven>();
+
+pub struct DaySeven;
+
+impl Solution for DaySeven {
+    type Input = Vec<Equation>;
+    type PartOne = u64;
+    type PartTwo = u64;
+    type Error = SyntaxError;
+
+    fn parse(input: &str) -> std::result::Result<Self::Input, Self::Error> {
+        parse_equations(input)
+    }
+
+    fn part_one(input: &Self::Input) -> std::result::Result<Self::PartOne, Self::Error> {
+        Ok(part_1(input))
+    }
+
+    fn part_two(input: &Self::Input) -> std::result::Result<Self::PartTwo, Self::Error> {
+        Ok(part_2(input))
+    }
+}
+
+/// `size` equations of two to seven parts. Most answers come from combining the parts with
+/// random operators (concatenation included), the others are off by a bit.
+impl Generate for DaySeven {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                let parts: Vec<u64> = (0..rng.range(2..8))
+                    .map(|_| rng.range(1..100) as u64)
+                    .collect();
+
+                let mut answer = parts[0];
+                for part in &parts[1..] {
+                    answer = match rng.below(3) {
+                        0 => answer + part,
+                        1 => answer * part,
+                        _ => format!("{answer}{part}")
+                            .parse()
+                            .expect("At most 14 digits"),
+                    };
+                }
+                if rng.chance(0.3) {
+                    answer += rng.range(1..10) as u64;
+                }
+
+                let parts: Vec<_> = parts.iter().map(u64::to_string).collect();
+                format!("{answer}: {}\n", parts.join(" "))
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+pub struct Equation {
+    pub answer: u64,
+    pub parts: Vec<u16>,
+}
+
+pub fn part_1(equations: &[Equation]) -> u64 {
+    let solvable_equation_sum: u64 = equations
+        .iter()
+        .filter_map(|e| check_is_solvable(e).then_some(e.answer))
+        .sum();
+
+    solvable_equation_sum
+}
+
+pub fn part_2(equations: &[Equation]) -> u64 {
+    let solvable_equation_sum: u64 = equations
+        .par_iter()
+        .filter_map(|e| check_is_solvable_part2(e).then_some(e.answer))
+        .sum();
+
+    solvable_equation_sum
+}
+
+pub fn check_is_solvable(equation: &Equation) -> bool {
+    let amount_of_operants = equation.parts.len() as u32 - 1;
+
+    // In binary there are two options. In this case there are two options as well: '+' and '*'
+    let possible_variations = u64::pow(2, amount_of_operants);
+
+    (0..possible_variations).any(|operant_configuration| {
+        let mut accumulated = *equation
+            .parts
+            .first()
+            .expect("every equation needs at least one part") as u64;
+
+        for (index, next_item) in equation.parts.iter().skip(1).enumerate() {
+            if accumulated > equation.answer {
+                break;
+            }
+
+            let use_multiply = operant_configuration >> index & 1 == 1;
+
+            if use_multiply {
+                accumulated = accumulated.saturating_mul((*next_item) as u64)
+            } else {
+                accumulated += (*next_item) as u64
+            }
+        }
+
+        accumulated == equation.answer
+    })
+}
+
+pub fn check_is_solvable_part2(equation: &Equation) -> bool {
+    let amount_of_operants = equation.parts.len() as u32 - 1;
+
+    let possible_variations = usize::pow(3, amount_of_operants);
+    let operator_list = OperatorList::new();
+
+    operator_list
+        .take(possible_variations)
+        .any(|operant_configuration| {
+            let mut accumulated = *equation
+                .parts
+                .first()
+                .expect("every equation needs at least one part")
+                as u64;
+
+            for (index, next_item) in equation.parts.iter().skip(1).enumerate() {
+                if accumulated > equation.answer {
+                    break;
+                }
+
+                match operant_configuration.at(index) {
+                    Operator::Sum => accumulated += *next_item as u64,
+                    Operator::Multiply => {
+                        accumulated = accumulated.saturating_mul(*next_item as u64)
+                    }
+                    Operator::Concat => {
+                        // A number too large to fit is also too large for the answer
+                        accumulated = format!("{accumulated}{next_item}")
+                            .parse()
+                            .unwrap_or(u64::MAX)
+                    }
+                }
+            }
+
+            accumulated == equation.answer
+        })
+}
+
+pub fn parse_equations(input: &str) -> std::result::Result<Vec<Equation>, SyntaxError> {
+    parse_all(lines(parse_equation), input)
+}
+
+fn parse_equation(input: &mut &str) -> Result<Equation> {
+    let start = input.checkpoint();
+    let (answer, parts) =
+        separated_pair(unsigned(), (':', space1), unsigned_list(space1)).parse_next(input)?;
+
+    if parts.len() > MAX_PARTS {
+        input.reset(&start);
+        return fail
+            .context(StrContext::Expected(StrContextValue::Description(
+                TOO_MANY_PARTS.as_str(),
+            )))
+            .parse_next(input);
+    }
+    Ok(Equation { answer, parts })
+}
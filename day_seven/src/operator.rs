@@ -81,64 +81,48 @@ mod tests {
         assert_eq!(next.operators.len(), 1);
         assert!(matches!(next.operators[0], Operator::Concat));
 
-        // Fourth iteration (wraps around and adds new item)
+        // Fourth iteration (wraps around and adds new item, starting after `Sum` as a missing
+        // operator already counts as one)
         let next = operator_list.next().unwrap();
         assert_eq!(next.operators.len(), 2);
         assert!(matches!(next.operators[0], Operator::Sum));
-        assert!(matches!(next.operators[1], Operator::Sum));
+        assert!(matches!(next.operators[1], Operator::Multiply));
 
         // Fifth iteration
         let next = operator_list.next().unwrap();
         assert_eq!(next.operators.len(), 2);
         assert!(matches!(next.operators[0], Operator::Multiply));
-        assert!(matches!(next.operators[1], Operator::Sum));
+        assert!(matches!(next.operators[1], Operator::Multiply));
 
         // Sixth iteration
         let next = operator_list.next().unwrap();
         assert_eq!(next.operators.len(), 2);
         assert!(matches!(next.operators[0], Operator::Concat));
-        assert!(matches!(next.operators[1], Operator::Sum));
+        assert!(matches!(next.operators[1], Operator::Multiply));
 
         // Seventh iteration
         let next = operator_list.next().unwrap();
         assert_eq!(next.operators.len(), 2);
         assert!(matches!(next.operators[0], Operator::Sum));
-        assert!(matches!(next.operators[1], Operator::Multiply));
+        assert!(matches!(next.operators[1], Operator::Concat));
 
         // Eighth iteration
         let next = operator_list.next().unwrap();
         assert_eq!(next.operators.len(), 2);
         assert!(matches!(next.operators[0], Operator::Multiply));
-        assert!(matches!(next.operators[1], Operator::Multiply));
+        assert!(matches!(next.operators[1], Operator::Concat));
 
         // Ninth iteration
         let next = operator_list.next().unwrap();
         assert_eq!(next.operators.len(), 2);
         assert!(matches!(next.operators[0], Operator::Concat));
-        assert!(matches!(next.operators[1], Operator::Multiply));
-
-        // Tenth iteration
-        let next = operator_list.next().unwrap();
-        assert_eq!(next.operators.len(), 2);
-        assert!(matches!(next.operators[0], Operator::Sum));
         assert!(matches!(next.operators[1], Operator::Concat));
 
-        // Tenth iteration
-        let next = operator_list.next().unwrap();
-        assert_eq!(next.operators.len(), 2);
-        assert!(matches!(next.operators[0], Operator::Multiply));
-        assert!(matches!(next.operators[1], Operator::Concat));
-        // Tenth iteration
-        let next = operator_list.next().unwrap();
-        assert_eq!(next.operators.len(), 2);
-        assert!(matches!(next.operators[0], Operator::Concat));
-        assert!(matches!(next.operators[1], Operator::Concat));
         // Tenth iteration
         let next = operator_list.next().unwrap();
         assert_eq!(next.operators.len(), 3);
         assert!(matches!(next.operators[0], Operator::Sum));
         assert!(matches!(next.operators[1], Operator::Sum));
-        println!("tester {:?}", next.operators[2]);
         assert!(matches!(next.operators[2], Operator::Multiply));
     }
 }
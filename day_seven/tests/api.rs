@@ -0,0 +1,29 @@
+use day_seven::{check_is_solvable, check_is_solvable_part2, parse_equations, MAX_PARTS};
+
+fn ones(amount: usize) -> String {
+    vec!["1"; amount].join(" ")
+}
+
+#[test]
+fn long_equations_are_solvable() {
+    let equations = parse_equations(&format!("12: {}\n13: {}\n", ones(12), ones(12))).unwrap();
+
+    assert!(check_is_solvable(&equations[0]));
+    assert!(!check_is_solvable(&equations[1]));
+}
+
+#[test]
+fn equations_with_the_most_numbers_are_solved() {
+    // No combination comes close to the answer, so every one of them is tried
+    let equations = parse_equations(&format!("1000000000000000: {}\n", ones(MAX_PARTS))).unwrap();
+
+    assert!(!check_is_solvable(&equations[0]));
+    assert!(!check_is_solvable_part2(&equations[0]));
+}
+
+#[test]
+fn too_many_numbers_are_rejected() {
+    let error = parse_equations(&format!("1: {}\n", ones(MAX_PARTS + 1))).unwrap_err();
+
+    assert_eq!(error.expected, [format!("at most {MAX_PARTS} numbers")]);
+}
@@ -0,0 +1,44 @@
+use aoc_common::{random::Rng, solution::Generate};
+use day_seven::{check_is_solvable, check_is_solvable_part2, parse_equations, DaySeven};
+
+const CASES: u64 = 30;
+
+/// Every value the parts can be combined into, from left to right.
+fn outcomes(parts: &[u16], concatenate: bool) -> Vec<u64> {
+    let mut outcomes = vec![parts[0] as u64];
+
+    for part in parts[1..].iter().map(|part| *part as u64) {
+        outcomes = outcomes
+            .iter()
+            .flat_map(|value| {
+                let mut next = vec![value + part, value * part];
+                if concatenate {
+                    next.push(format!("{value}{part}").parse().unwrap());
+                }
+                next
+            })
+            .collect();
+    }
+
+    outcomes
+}
+
+#[test]
+fn solvable_equations_match_the_reference() {
+    for seed in 0..CASES {
+        let equations = parse_equations(&DaySeven::generate(&mut Rng::new(seed), 30)).unwrap();
+
+        for equation in &equations {
+            assert_eq!(
+                check_is_solvable(equation),
+                outcomes(&equation.parts, false).contains(&equation.answer),
+                "seed {seed}, {equation:?}"
+            );
+            assert_eq!(
+                check_is_solvable_part2(equation),
+                outcomes(&equation.parts, true).contains(&equation.answer),
+                "seed {seed}, {equation:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,290 @@
+use aoc_common::{
+    error::SyntaxError,
+    input::InputFiles,
+    progress::Progress,
+    random::Rng,
+    solution::{Day, Generate, Solution},
+};
+use rayon::prelude::*;
+use std::ops::BitXor;
+
+use crate::{
+    types::{ComboOperand, ComputerState, Instruction},
+    u3::U3,
+};
+
+pub mod parser;
+pub mod types;
+pub mod u3;
+
+pub const INPUT_FILES: InputFiles = InputFiles::new(env!("CARGO_MANIFEST_DIR"), "input_small.txt");
+
+pub const DAY: Day = Day::new::<DaySeventeen>(17, &INPUT_FILES).with_generator::<DaySeventeen>();
+
+pub struct DaySeventeen;
+
+impl Solution for DaySeventeen {
+    type Input = ComputerState;
+    type PartOne = String;
+    type PartTwo = u64;
+    type Error = ComputerError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(parser::parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne, Self::Error> {
+        part1(input.to_owned())
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo, Self::Error> {
+        part2(input.to_owned())
+    }
+}
+
+/// A program shaped like the puzzle input: a loop of `size` random instructions (at least one
+/// of them `out`), after which `adv` divides register A by 2, 4 or 8 and `jnz 0` jumps back to
+/// the start. Register A shrinks every time around, so the program always halts.
+impl Generate for DaySeventeen {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        const BXL: i64 = 1;
+        const BST: i64 = 2;
+        const BXC: i64 = 4;
+        const OUT: i64 = 5;
+        const BDV: i64 = 6;
+        const CDV: i64 = 7;
+
+        let mut program: Vec<i64> = (0..size.max(1))
+            .flat_map(|_| {
+                let opcode = *rng.pick(&[BXL, BST, BXC, OUT, BDV, CDV]);
+                // Combo operands never use the reserved 7
+                let operand = match opcode {
+                    BXL | BXC => rng.range(0..8),
+                    _ => rng.range(0..7),
+                };
+                [opcode, operand]
+            })
+            .collect();
+
+        if !program.chunks(2).any(|instruction| instruction[0] == OUT) {
+            let last = program.len() - 2;
+            program[last..].copy_from_slice(&[OUT, rng.range(0..7)]);
+        }
+        program.extend([0, rng.range(1..4), 3, 0]);
+
+        let program: Vec<_> = program.iter().map(i64::to_string).collect();
+        format!(
+            "Register A: {}\nRegister B: {}\nRegister C: {}\n\nProgram: {}\n",
+            rng.range(0..1 << 30),
+            rng.range(0..8),
+            rng.range(0..8),
+            program.join(",")
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ComputerError {
+    #[error("{0}")]
+    Syntax(#[from] SyntaxError),
+    #[error("the instruction at {instruction_pointer} uses the reserved combo operand 7")]
+    ReservedOperand { instruction_pointer: usize },
+    #[error("the instruction at {instruction_pointer} has no operand")]
+    MissingOperand { instruction_pointer: usize },
+    #[error("no value for register A in the searched range makes the program output itself")]
+    NotFound,
+}
+
+pub fn part1(start_state: ComputerState) -> Result<String, ComputerError> {
+    Ok(run(start_state)?.join(","))
+}
+
+/// Runs the program until it halts by moving the instruction pointer past its end, returning
+/// everything it has output.
+pub fn run(mut state: ComputerState) -> Result<Vec<U3>, ComputerError> {
+    let mut output = vec![];
+
+    while state.instruction_pointer < state.operations.len() {
+        compute(&mut state, &mut output)?;
+    }
+
+    Ok(output)
+}
+
+pub fn part2(start_state: ComputerState) -> Result<u64, ComputerError> {
+    let expected_output = start_state.operations.to_owned();
+
+    let candidates = 2u64.pow(45)..2u64.pow(48) - 1;
+    let progress = Progress::new(
+        "register A values checked",
+        Some(candidates.end - candidates.start),
+    );
+
+    let found = candidates
+        .into_par_iter()
+        .map_init(
+            || progress.counter(),
+            |counter, reg_a| {
+                counter.tick();
+
+                // Create new state to test in this iteration
+                let mut state = ComputerState {
+                    reg_a,
+                    ..start_state.to_owned()
+                };
+
+                // Create new output buffer for this iteration
+                let mut output = vec![];
+
+                while state.instruction_pointer < state.operations.len() {
+                    // A broken program is broken for every value, so stop searching
+                    if let Err(error) = compute(&mut state, &mut output) {
+                        return Some(Err(error));
+                    }
+
+                    let Some(max_output_index) = output.len().checked_sub(1) else {
+                        continue;
+                    };
+
+                    // Short circuit if latest addition isn't same as expected value
+                    if expected_output.get(max_output_index) != output.get(max_output_index) {
+                        break;
+                    }
+                }
+
+                if output.len() == expected_output.len() && output.last() == expected_output.last()
+                {
+                    Some(Ok(reg_a))
+                } else {
+                    None
+                }
+            },
+        )
+        .flatten()
+        .take_any(1)
+        .collect::<Vec<_>>();
+
+    progress.finish();
+
+    found
+        .into_iter()
+        .next()
+        .unwrap_or(Err(ComputerError::NotFound))
+}
+
+/// Executes the instruction at the instruction pointer, which has to point into the program.
+pub fn compute(
+    state: &mut ComputerState,
+    output_buffer: &mut Vec<U3>,
+) -> Result<(), ComputerError> {
+    let instruction_index = state.instruction_pointer;
+    let instruction: Instruction = state.operations[instruction_index].into();
+    let mut jumped = false;
+
+    match instruction {
+        Instruction::Adv => state.reg_a = division_instruction(state)?,
+        Instruction::Bxl => {
+            state.reg_b = state
+                .reg_b
+                .bitxor(literal_operand_value(state)?.get() as u64)
+        }
+        Instruction::Bst => state.reg_b = combo_operand_value(state)? % 8,
+        Instruction::Jnz => {
+            if state.reg_a != 0 {
+                jumped = true;
+                state.instruction_pointer = literal_operand_value(state)?.get() as usize;
+            }
+        }
+        Instruction::Bxc => state.reg_b = state.reg_b.bitxor(state.reg_c),
+        Instruction::Out => output_buffer.push(
+            U3::new(combo_operand_value(state)? % 8).expect("A value modulo 8 fits in three bits"),
+        ),
+        Instruction::Bdv => state.reg_b = division_instruction(state)?,
+        Instruction::Cdv => state.reg_c = division_instruction(state)?,
+    }
+
+    if !jumped {
+        state.instruction_pointer += 2;
+    }
+
+    Ok(())
+}
+
+fn division_instruction(state: &ComputerState) -> Result<u64, ComputerError> {
+    let numerator = state.reg_a;
+
+    // Dividing by a power of two that does not fit always leaves nothing
+    let quotient = u32::try_from(combo_operand_value(state)?)
+        .ok()
+        .and_then(|exponent| 2_u64.checked_pow(exponent))
+        .map_or(0, |denominator| numerator / denominator);
+
+    Ok(quotient)
+}
+
+fn combo_operand_value(state: &ComputerState) -> Result<u64, ComputerError> {
+    let operand: ComboOperand = literal_operand_value(state)?.into();
+
+    match operand {
+        ComboOperand::Literal0 => Ok(0),
+        ComboOperand::Literal1 => Ok(1),
+        ComboOperand::Literal2 => Ok(2),
+        ComboOperand::Literal3 => Ok(3),
+        ComboOperand::ValueOfA => Ok(state.reg_a),
+        ComboOperand::ValueOfB => Ok(state.reg_b),
+        ComboOperand::ValueOfC => Ok(state.reg_c),
+        ComboOperand::Reserved => Err(ComputerError::ReservedOperand {
+            instruction_pointer: state.instruction_pointer,
+        }),
+    }
+}
+
+fn literal_operand_value(state: &ComputerState) -> Result<U3, ComputerError> {
+    let operant_index = state.instruction_pointer + 1;
+    state
+        .operations
+        .get(operant_index)
+        .copied()
+        .ok_or(ComputerError::MissingOperand {
+            instruction_pointer: state.instruction_pointer,
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{ComputerError, parser::parse_input, part1};
+
+    #[test]
+    fn reserved_combo_operand_is_an_error() {
+        let state =
+            parse_input("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 5,7\n").unwrap();
+
+        assert_eq!(
+            part1(state),
+            Err(ComputerError::ReservedOperand {
+                instruction_pointer: 0
+            })
+        );
+    }
+
+    #[test]
+    fn missing_operand_is_an_error() {
+        let state =
+            parse_input("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 5,4,5\n").unwrap();
+
+        assert_eq!(
+            part1(state),
+            Err(ComputerError::MissingOperand {
+                instruction_pointer: 2
+            })
+        );
+    }
+
+    #[test]
+    fn syntax_errors_have_a_location() {
+        let error = parse_input("Register A: 1\nRegister B: x\n").unwrap_err();
+
+        assert_eq!(error.location.line, 2);
+        assert_eq!(error.location.column, 13);
+    }
+}
@@ -1,7 +1,9 @@
+use aoc_common::error::SyntaxError;
+use aoc_parse::{blank_line, list, parse_all, unsigned};
 use winnow::Result;
-use winnow::ascii::{dec_uint, newline};
-use winnow::combinator::{preceded, separated, terminated};
-use winnow::error::ContextError;
+use winnow::ascii::line_ending;
+use winnow::combinator::{preceded, terminated};
+use winnow::error::{ContextError, StrContext};
 use winnow::prelude::*;
 use winnow::token::literal;
 
@@ -11,25 +13,22 @@ use crate::u3::U3;
 fn register<'a>(name: &'static str) -> impl Parser<&'a str, u64, ContextError> {
     preceded(
         (literal("Register "), literal(name), literal(": ")),
-        dec_uint,
+        unsigned(),
     )
 }
 
 fn program_ops(input: &mut &str) -> Result<Vec<U3>> {
-    separated(
-        1..,
-        dec_uint::<_, u8, _>
-            .verify(|&v| v <= 7)
-            .map(|n| U3::new(n).unwrap()),
-        literal(","),
-    )
-    .parse_next(input)
+    let operation = unsigned::<u8>()
+        .verify_map(U3::new)
+        .context(StrContext::Label("3-bit number"));
+
+    list(operation, literal(",")).parse_next(input)
 }
 
 pub fn computer_state(input: &mut &str) -> Result<ComputerState> {
-    let reg_a = terminated(register("A"), newline).parse_next(input)?;
-    let reg_b = terminated(register("B"), newline).parse_next(input)?;
-    let reg_c = terminated(register("C"), (newline, newline)).parse_next(input)?;
+    let reg_a = terminated(register("A"), line_ending).parse_next(input)?;
+    let reg_b = terminated(register("B"), line_ending).parse_next(input)?;
+    let reg_c = terminated(register("C"), blank_line).parse_next(input)?;
 
     let operations = preceded(literal("Program: "), program_ops).parse_next(input)?;
 
@@ -41,3 +40,8 @@ pub fn computer_state(input: &mut &str) -> Result<ComputerState> {
         operations,
     })
 }
+
+/// Parses the whole input, reporting where it does not describe a computer.
+pub fn parse_input(input: &str) -> std::result::Result<ComputerState, SyntaxError> {
+    parse_all(computer_state, input)
+}
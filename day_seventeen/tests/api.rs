@@ -0,0 +1,24 @@
+use day_seventeen::{compute, parser::parse_input, run, u3::U3};
+
+#[test]
+fn runs_the_example_program() {
+    let state = parse_input(include_str!("../input_small.txt")).unwrap();
+
+    let output: Vec<_> = run(state).unwrap().into_iter().map(U3::get).collect();
+
+    assert_eq!(output, [5, 7, 3, 0]);
+}
+
+#[test]
+fn steps_through_single_instructions() {
+    // bst 4 (b = a % 8), then out 5 (output b)
+    let mut state =
+        parse_input("Register A: 13\nRegister B: 0\nRegister C: 0\n\nProgram: 2,4,5,5\n").unwrap();
+    let mut output = vec![];
+
+    compute(&mut state, &mut output).unwrap();
+    assert_eq!((state.reg_b, state.instruction_pointer), (5, 2));
+
+    compute(&mut state, &mut output).unwrap();
+    assert_eq!(output, [U3::new(5u8).unwrap()]);
+}
@@ -0,0 +1,59 @@
+use aoc_common::{random::Rng, solution::Generate};
+use day_seventeen::{DaySeventeen, parser::parse_input, part1};
+
+const CASES: u64 = 100;
+
+/// Interprets the program a step at a time, straight from the numbers of the input.
+fn reference_output(input: &str) -> String {
+    let numbers: Vec<u64> = input
+        .split(|c: char| !c.is_ascii_digit())
+        .filter(|number| !number.is_empty())
+        .map(|number| number.parse().unwrap())
+        .collect();
+    let (mut registers, program) = ([numbers[0], numbers[1], numbers[2]], &numbers[3..]);
+    let (mut pointer, mut output) = (0, vec![]);
+
+    while pointer + 1 < program.len() {
+        let (opcode, operand) = (program[pointer], program[pointer + 1]);
+        // Only read for instructions with a combo operand, which is never 7
+        let combo = |registers: [u64; 3]| match operand {
+            0..=3 => operand,
+            _ => registers[operand as usize - 4],
+        };
+        let divided = |registers: [u64; 3]| registers[0] >> combo(registers).min(63);
+
+        match opcode {
+            0 => registers[0] = divided(registers),
+            1 => registers[1] ^= operand,
+            2 => registers[1] = combo(registers) % 8,
+            3 if registers[0] != 0 => {
+                pointer = operand as usize;
+                continue;
+            }
+            3 => {}
+            4 => registers[1] ^= registers[2],
+            5 => output.push((combo(registers) % 8).to_string()),
+            6 => registers[1] = divided(registers),
+            _ => registers[2] = divided(registers),
+        }
+        pointer += 2;
+    }
+
+    output.join(",")
+}
+
+// Part two searches for a value of register A that makes the puzzle program output itself,
+// which random programs never do, so only part one is compared.
+#[test]
+fn output_matches_the_reference() {
+    for seed in 0..CASES {
+        let input = DaySeventeen::generate(&mut Rng::new(seed), 1 + seed as usize % 8);
+        let state = parse_input(&input).unwrap();
+
+        assert_eq!(
+            part1(state).unwrap(),
+            reference_output(&input),
+            "seed {seed}\n{input}"
+        );
+    }
+}
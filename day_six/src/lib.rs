@@ -0,0 +1,273 @@
+use std::{convert::Infallible, io};
+
+use aoc_common::{
+    error::SyntaxError,
+    input::InputFiles,
+    random::Rng,
+    solution::{Day, Generate, Solution, Visualize},
+    visualize::{Cell, Color, Frame, Visualizer},
+};
+use aoc_grid::{Direction, Grid, IVec2};
+use aoc_parse::{grid_of, parse_all};
+use rayon::prelude::*;
+use winnow::error::StrContextValue;
+
+pub const INPUT_FILES: InputFiles = InputFiles::new(env!("CARGO_MANIFEST_DIR"), "input_small.txt");
+
+pub const DAY: Day = Day::new::<DaySix>(6, &INPUT_FILES)
+    .with_visualization::<DaySix>()
+    .with_generator::<DaySix>();
+
+pub struct DaySix;
+
+impl Solution for DaySix {
+    type Input = Maze;
+    type PartOne = usize;
+    type PartTwo = usize;
+    type Error = ParseMazeError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne, Self::Error> {
+        Ok(part_1(input.to_owned()))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo, Self::Error> {
+        Ok(part_2(input.to_owned()))
+    }
+}
+
+impl Visualize for DaySix {
+    fn visualize(input: &Self::Input, visualizer: &mut Visualizer) -> io::Result<()> {
+        let mut maze = input.to_owned();
+        let mut steps = 0;
+
+        visualizer.show(&maze.frame(maze.guard, Direction::North))?;
+        walk_guard(&mut maze, |maze, guard, direction| {
+            steps += 1;
+            let frame = maze.frame(guard, direction).with_caption(format!(
+                "Steps: {steps}, visited: {}",
+                count_guard_locations(maze)
+            ));
+            visualizer.show(&frame)
+        })
+    }
+}
+
+/// A maze of `size` by `size` chambers with random obstructions and a single guard. Mazes in
+/// which the guard walks in a loop are thrown away, as the guard has to leave the maze.
+impl Generate for DaySix {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        loop {
+            let density = rng.range(5..25) as f64 / 100.0;
+            let mut chambers: Vec<Vec<char>> = (0..size)
+                .map(|_| {
+                    (0..size)
+                        .map(|_| if rng.chance(density) { '#' } else { '.' })
+                        .collect()
+                })
+                .collect();
+            chambers[rng.index(size)][rng.index(size)] = '^';
+
+            let text: String = chambers
+                .iter()
+                .map(|row| row.iter().collect::<String>() + "\n")
+                .collect();
+            let mut maze = parse_input(&text).expect("Generated mazes are valid");
+            if !detect_loop_in_maze(&mut maze) {
+                return text;
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseMazeError {
+    #[error("{0}")]
+    Syntax(#[from] SyntaxError),
+    #[error("the maze should contain exactly one guard (^), found {0}")]
+    GuardCount(usize),
+}
+
+pub fn parse_input(input: &str) -> Result<Maze, ParseMazeError> {
+    input.parse::<Maze>()
+}
+
+pub fn part_1(mut maze: Maze) -> usize {
+    let Ok(()) = walk_guard(&mut maze, |_, _, _| Ok::<_, Infallible>(()));
+
+    count_guard_locations(&maze)
+}
+
+/// Walks the guard out of the maze, marking its path. After every step `on_step` gets the maze,
+/// the location of the guard and the direction it is facing, an error stops the walk.
+fn walk_guard<E>(
+    maze: &mut Maze,
+    mut on_step: impl FnMut(&Maze, IVec2, Direction) -> Result<(), E>,
+) -> Result<(), E> {
+    let mut guard_direction = Direction::North;
+    let mut current_guard_location = maze.guard;
+
+    loop {
+        let next_chamber_index = current_guard_location + guard_direction.vector();
+        if let Some(next_chamber) = maze.get_at(&next_chamber_index) {
+            if matches!(next_chamber, Chamber::Obstruction) {
+                guard_direction = guard_direction.clockwise();
+                continue;
+            }
+            maze.mark_guard_path(&next_chamber_index, guard_direction);
+            current_guard_location = next_chamber_index;
+            on_step(maze, current_guard_location, guard_direction)?;
+        } else {
+            return Ok(());
+        }
+    }
+}
+
+pub fn part_2(maze: Maze) -> usize {
+    let mut locations_to_check = vec![];
+
+    // Find out which other obstacles can be placed in chambers of the maze
+    for (location, chamber) in maze.chambers.iter() {
+        if !matches!(chamber, Chamber::Obstruction | Chamber::Guard(_)) {
+            locations_to_check.push(location);
+        }
+    }
+
+    // for each location, try out if it contains a loop. Every location gets its own copy of the
+    // maze, so they can be checked in parallel
+    let loop_count = locations_to_check
+        .par_iter()
+        .filter(|location| {
+            let mut maze_to_check = maze.clone();
+            maze_to_check.mark_obstructed(location);
+            detect_loop_in_maze(&mut maze_to_check)
+        })
+        .count();
+
+    loop_count
+}
+
+fn detect_loop_in_maze(maze: &mut Maze) -> bool {
+    let mut guard_direction = Direction::North;
+    let mut current_guard_location = maze.guard;
+
+    let mut loop_detected = false;
+
+    loop {
+        let next_chamber_index = current_guard_location + guard_direction.vector();
+        if let Some(next_chamber) = maze.get_at(&next_chamber_index) {
+            match next_chamber {
+                Chamber::Obstruction => {
+                    guard_direction = guard_direction.clockwise();
+                    continue;
+                }
+                Chamber::Guard(next_direction) if *next_direction == guard_direction => {
+                    loop_detected = true;
+                    break;
+                }
+                _ => (),
+            }
+            maze.mark_guard_path(&next_chamber_index, guard_direction);
+            current_guard_location = next_chamber_index;
+        } else {
+            break;
+        }
+    }
+
+    loop_detected
+}
+
+fn count_guard_locations(maze: &Maze) -> usize {
+    maze.chambers
+        .iter()
+        .filter(|(_, chamber)| matches!(chamber, Chamber::Guard(_)))
+        .count()
+}
+
+#[derive(Clone, Debug)]
+enum Chamber {
+    Empty,
+    Obstruction,
+    Guard(Direction),
+}
+
+#[derive(Debug, Clone)]
+pub struct Maze {
+    chambers: Grid<Chamber>,
+    /// Where the guard starts, facing north
+    guard: IVec2,
+}
+
+impl Maze {
+    fn get_at(&self, point: &IVec2) -> Option<&Chamber> {
+        self.chambers.get(*point)
+    }
+
+    fn mark_guard_path(&mut self, point: &IVec2, direction: Direction) {
+        self.chambers[*point] = Chamber::Guard(direction);
+    }
+
+    fn mark_obstructed(&mut self, point: &IVec2) {
+        self.chambers[*point] = Chamber::Obstruction;
+    }
+
+    /// Draws the maze with the path walked so far and the guard facing `direction`.
+    fn frame(&self, guard: IVec2, direction: Direction) -> Frame {
+        Frame::from_rows((0..self.chambers.height() as i32).map(|y| {
+            (0..self.chambers.width() as i32).map(move |x| {
+                let location = IVec2::new(x, y);
+                if location == guard {
+                    let symbol = match direction {
+                        Direction::North => '^',
+                        Direction::East => '>',
+                        Direction::South => 'v',
+                        _ => '<',
+                    };
+                    return Cell::new(symbol, Color::Red);
+                }
+
+                match self.chambers[location] {
+                    Chamber::Empty => Cell::plain('.'),
+                    Chamber::Obstruction => Cell::new('#', Color::Gray),
+                    Chamber::Guard(_) => Cell::new('X', Color::Yellow),
+                }
+            })
+        }))
+    }
+}
+
+impl std::str::FromStr for Maze {
+    type Err = ParseMazeError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        const CHAMBERS: &[StrContextValue] = &[
+            StrContextValue::CharLiteral('.'),
+            StrContextValue::CharLiteral('#'),
+            StrContextValue::CharLiteral('^'),
+        ];
+
+        let chambers = parse_all(
+            grid_of(CHAMBERS, |character| match character {
+                '.' => Some(Chamber::Empty),
+                '#' => Some(Chamber::Obstruction),
+                '^' => Some(Chamber::Guard(Direction::North)),
+                _ => None,
+            }),
+            input,
+        )?;
+
+        let guards: Vec<_> = chambers
+            .iter()
+            .filter(|(_, chamber)| matches!(chamber, Chamber::Guard(_)))
+            .map(|(location, _)| location)
+            .collect();
+
+        match guards[..] {
+            [guard] => Ok(Maze { chambers, guard }),
+            _ => Err(ParseMazeError::GuardCount(guards.len())),
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use std::collections::HashSet;
+
+use aoc_common::{random::Rng, solution::Generate};
+use day_six::{parse_input, part_1, part_2, DaySix};
+
+const CASES: u64 = 40;
+
+/// The locations the guard visits, `None` when it walks in a loop.
+fn walk(rows: &[Vec<u8>], extra_obstruction: Option<(i32, i32)>) -> Option<HashSet<(i32, i32)>> {
+    let start = rows
+        .iter()
+        .enumerate()
+        .find_map(|(y, row)| Some((row.iter().position(|c| *c == b'^')? as i32, y as i32)))
+        .unwrap();
+    let chamber = |(x, y): (i32, i32)| {
+        let row = rows.get(usize::try_from(y).ok()?)?;
+        let chamber = *row.get(usize::try_from(x).ok()?)?;
+        Some(chamber == b'#' || Some((x, y)) == extra_obstruction)
+    };
+
+    let (mut location, mut direction) = (start, (0, -1));
+    let mut states = HashSet::new();
+
+    while states.insert((location, direction)) {
+        let next = (location.0 + direction.0, location.1 + direction.1);
+        match chamber(next) {
+            None => return Some(states.into_iter().map(|(location, _)| location).collect()),
+            // Turn right
+            Some(true) => direction = (-direction.1, direction.0),
+            Some(false) => location = next,
+        }
+    }
+
+    None
+}
+
+/// Tries an obstruction in every empty chamber.
+fn reference_loops(rows: &[Vec<u8>]) -> usize {
+    (0..rows.len() as i32)
+        .flat_map(|y| (0..rows[0].len() as i32).map(move |x| (x, y)))
+        .filter(|&(x, y)| rows[y as usize][x as usize] == b'.')
+        .filter(|&location| walk(rows, Some(location)).is_none())
+        .count()
+}
+
+#[test]
+fn solutions_match_the_reference() {
+    for seed in 0..CASES {
+        let input = DaySix::generate(&mut Rng::new(seed), 1 + seed as usize / 2);
+        let maze = parse_input(&input).unwrap();
+        let rows: Vec<Vec<u8>> = input.lines().map(|row| row.as_bytes().to_vec()).collect();
+
+        let visited = walk(&rows, None).expect("The guard leaves generated mazes");
+        assert_eq!(part_1(maze.clone()), visited.len(), "seed {seed}\n{input}");
+        assert_eq!(part_2(maze), reference_loops(&rows), "seed {seed}\n{input}");
+    }
+}
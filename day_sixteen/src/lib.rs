@@ -0,0 +1,333 @@
+use std::{collections::HashSet, ops::Not};
+
+use aoc_common::{
+    image::{Image, Rgb},
+    input::InputFiles,
+    random::Rng,
+    solution::{Day, Draw, Generate, Solution},
+    visualize::Color,
+};
+use aoc_grid::Direction;
+use aoc_search::Search;
+
+pub mod parser;
+
+const TURN_COST: u32 = 1000;
+const MOVE_COST: u32 = 1;
+
+pub const INPUT_FILES: InputFiles = InputFiles::new(env!("CARGO_MANIFEST_DIR"), "small_input.txt");
+
+pub const DAY: Day = Day::new::<DaySixteen>(16, &INPUT_FILES)
+    .with_image::<DaySixteen>()
+    .with_generator::<DaySixteen>();
+
+pub struct DaySixteen;
+
+impl Solution for DaySixteen {
+    type Input = AoCInput;
+    type PartOne = u32;
+    type PartTwo = usize;
+    type Error = MazeError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(parser::parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne, Self::Error> {
+        first_star_second_try(input).ok_or(MazeError::Unreachable)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo, Self::Error> {
+        second_star(input).ok_or(MazeError::Unreachable)
+    }
+}
+
+impl Draw for DaySixteen {
+    /// Draws the walls and every tile that is part of one of the best paths.
+    fn draw(input: &Self::Input, _moment: Option<usize>) -> Result<Image, Self::Error> {
+        let search = dijkstra_with_turn_score(input.start.to_owned(), input.finish, &input.walls);
+        search.goal_distance().ok_or(MazeError::Unreachable)?;
+        let tiles = best_path_tiles(&search);
+
+        // The maze is surrounded by walls, so they span all of it
+        let width = input.walls.iter().map(|wall| wall.x).max().unwrap_or(0) + 1;
+        let height = input.walls.iter().map(|wall| wall.y).max().unwrap_or(0) + 1;
+
+        Ok(Image::from_fn(width, height, |x, y| {
+            let position = Position { x, y };
+            if input.walls.contains(&position) {
+                Color::Gray.into()
+            } else if tiles.contains(&position) {
+                Color::Red.into()
+            } else {
+                Rgb::WHITE
+            }
+        }))
+    }
+}
+
+/// A maze of `size` by `size` tiles (made odd, at least five), with the start in the bottom left
+/// and the end in the top right corner like in the puzzle. The corridors are carved as a maze
+/// with a single way between every two tiles, after which some walls are knocked down so there
+/// are several ways to the end.
+impl Generate for DaySixteen {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(5) | 1;
+        let mut tiles = vec![vec!['#'; size]; size];
+
+        // Corridors run along odd rows and columns, a wall is carved out between two of them
+        let mut carved: Vec<(usize, usize)> = vec![(1, size - 2)];
+        tiles[size - 2][1] = '.';
+        while let Some(&(x, y)) = carved.last() {
+            let mut neighbours: Vec<(usize, usize)> = [(2, 0), (0, 2), (-2, 0), (0, -2)]
+                .iter()
+                .map(|(dx, dy)| (x.wrapping_add_signed(*dx), y.wrapping_add_signed(*dy)))
+                .filter(|&(x, y)| x < size - 1 && y < size - 1 && tiles[y][x] == '#')
+                .collect();
+
+            if neighbours.is_empty() {
+                carved.pop();
+                continue;
+            }
+
+            rng.shuffle(&mut neighbours);
+            let (next_x, next_y) = neighbours[0];
+            tiles[(y + next_y) / 2][(x + next_x) / 2] = '.';
+            tiles[next_y][next_x] = '.';
+            carved.push((next_x, next_y));
+        }
+
+        for _ in 0..size {
+            let (x, y) = (rng.index(size - 2) + 1, rng.index(size - 2) + 1);
+            tiles[y][x] = '.';
+        }
+
+        tiles[size - 2][1] = 'S';
+        tiles[1][size - 2] = 'E';
+        tiles
+            .iter()
+            .map(|row| row.iter().collect::<String>() + "\n")
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum MazeError {
+    #[error(transparent)]
+    Parse(#[from] parser::ParseError),
+    #[error("the end (E) cannot be reached from the start (S)")]
+    Unreachable,
+}
+
+pub fn first_star_second_try(input: &AoCInput) -> Option<u32> {
+    dijkstra_with_turn_score(input.start.to_owned(), input.finish, &input.walls).goal_distance()
+}
+
+pub fn second_star(input: &AoCInput) -> Option<usize> {
+    let search = dijkstra_with_turn_score(input.start.to_owned(), input.finish, &input.walls);
+    search.goal_distance()?;
+
+    // The finish can be reached facing different ways, every one of them counts
+    // Draw them with `--image` to debug lower-then-expected values
+    Some(best_path_tiles(&search).len())
+}
+
+/// Every tile that is part of at least one of the best paths to the finish.
+pub fn best_path_tiles(search: &Search<PlayerState, u32>) -> HashSet<Position> {
+    search
+        .states_on_paths_to(search.goals())
+        .into_iter()
+        .map(|player| player.position)
+        .collect()
+}
+
+/// Searches the cheapest ways from the start to the goal, where turning costs a lot more than
+/// moving forward.
+pub fn dijkstra_with_turn_score(
+    start: PlayerState,
+    goal: Position,
+    walls: &HashSet<Position>,
+) -> Search<PlayerState, u32> {
+    aoc_search::dijkstra(
+        [start],
+        |player| {
+            get_possible_moves_dijkstra(player, walls)
+                .into_iter()
+                .map(|possible_move| (possible_move.to_player(), possible_move.cost))
+        },
+        |player| player.position == goal,
+    )
+}
+
+fn get_possible_moves_dijkstra(player: &PlayerState, walls: &HashSet<Position>) -> Vec<PlayerMove> {
+    Direction::CARDINAL
+        .iter()
+        .filter(|direction| direction != &&player.heading.opposite())
+        .filter_map(|direction| {
+            let possible_location_to_travel = player.position.travel(direction);
+            walls
+                .contains(&possible_location_to_travel)
+                .not()
+                .then(|| PlayerMove {
+                    heading: *direction,
+                    new_position: possible_location_to_travel,
+                    cost: calculate_move_cost(player.heading, *direction),
+                })
+        })
+        .collect()
+}
+
+pub fn calculate_move_cost(old_direction: Direction, new_direction: Direction) -> u32 {
+    let amount_of_turns = old_direction.quarter_turns_to(new_direction);
+
+    // Every step costs 1, every turn 1000
+    amount_of_turns * TURN_COST + MOVE_COST
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::error::Location;
+    use aoc_grid::Direction;
+
+    use crate::{
+        calculate_move_cost, first_star_second_try,
+        parser::{ParseError, parse_input},
+    };
+
+    #[test]
+    fn missing_finish_is_an_error() {
+        assert_eq!(
+            parse_input("#####\n#S..#\n#####\n").err(),
+            Some(ParseError::MissingFinish)
+        );
+    }
+
+    #[test]
+    fn maze_has_to_be_surrounded_by_walls() {
+        let Some(ParseError::OpenBorder(error)) = parse_input("#####\n#S.E.\n#####\n").err() else {
+            panic!("An open border should be an error");
+        };
+
+        assert_eq!(error.location, Location { line: 2, column: 5 });
+    }
+
+    #[test]
+    fn walled_off_finish_is_unreachable() {
+        let input = parse_input("#####\n#S#E#\n#####\n").unwrap();
+
+        assert_eq!(first_star_second_try(&input), None);
+    }
+
+    #[test]
+    fn north_to_east_cost() {
+        let result = calculate_move_cost(Direction::North, Direction::East);
+
+        assert_eq!(result, 1001)
+    }
+
+    #[test]
+    fn east_to_north_cost() {
+        let result = calculate_move_cost(Direction::East, Direction::North);
+
+        assert_eq!(result, 1001)
+    }
+
+    #[test]
+    fn south_to_north_cost() {
+        let result = calculate_move_cost(Direction::North, Direction::South);
+
+        assert_eq!(result, 2001)
+    }
+
+    #[test]
+    fn north_to_south_cost() {
+        let result = calculate_move_cost(Direction::South, Direction::North);
+
+        assert_eq!(result, 2001)
+    }
+
+    #[test]
+    fn east_to_east_cost() {
+        let result = calculate_move_cost(Direction::East, Direction::East);
+
+        assert_eq!(result, 1)
+    }
+
+    #[test]
+    fn east_to_west_cost() {
+        let result = calculate_move_cost(Direction::East, Direction::West);
+
+        assert_eq!(result, 2001)
+    }
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Debug, Copy, PartialOrd, Ord)]
+pub struct Position {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Position {
+    pub fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    pub fn travel(&self, direction: &Direction) -> Self {
+        let step = direction.vector();
+
+        // Parsing checks the maze is surrounded by walls, so we never travel beyond the top or
+        // left side
+        Self {
+            x: self
+                .x
+                .checked_add_signed(step.x as isize)
+                .expect("Walls should stop travelling left of the maze"),
+            y: self
+                .y
+                .checked_add_signed(step.y as isize)
+                .expect("Walls should stop travelling above the maze"),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct PlayerMove {
+    heading: Direction,
+    new_position: Position,
+    cost: u32,
+}
+
+impl PlayerMove {
+    pub fn to_player(&self) -> PlayerState {
+        PlayerState {
+            position: self.new_position,
+            heading: self.heading,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, PartialOrd, Eq, Hash, Ord, Clone)]
+pub struct PlayerState {
+    pub position: Position,
+    pub heading: Direction,
+}
+
+pub struct AoCInput {
+    pub walls: HashSet<Position>,
+    pub start: PlayerState,
+    pub finish: Position,
+}
+
+impl AoCInput {
+    pub fn new(walls: HashSet<Position>, start: Position, finish: Position) -> Self {
+        Self {
+            walls,
+            // Going to the east is defined in AoC text
+            start: PlayerState {
+                position: start,
+                heading: Direction::East,
+            },
+            finish,
+        }
+    }
+}
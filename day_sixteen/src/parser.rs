@@ -1,14 +1,46 @@
 use std::collections::HashSet;
 
+use aoc_common::error::{Location, SyntaxError};
+
 use crate::{AoCInput, Position};
 
-pub fn parse_input(input: &str) -> AoCInput {
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseError {
+    #[error("the maze has no start (S)")]
+    MissingStart,
+    #[error("the maze has no end (E)")]
+    MissingFinish,
+    #[error("{0}")]
+    OpenBorder(#[source] SyntaxError),
+}
+
+pub fn parse_input(input: &str) -> Result<AoCInput, ParseError> {
     let mut start = None;
     let mut finish = None;
     let mut walls = HashSet::new();
 
+    let last_line_index = input.lines().count().saturating_sub(1);
+
     for (line_index, line) in input.lines().enumerate() {
+        let last_column_index = line.chars().count().saturating_sub(1);
+
         for (column_index, char) in line.chars().enumerate() {
+            // Walking is only bounded by walls, so there can be no way out
+            let is_border = line_index == 0
+                || line_index == last_line_index
+                || column_index == 0
+                || column_index == last_column_index;
+            if is_border && char != '#' {
+                let location = Location {
+                    line: line_index + 1,
+                    column: column_index + 1,
+                };
+                return Err(ParseError::OpenBorder(
+                    SyntaxError::at(location, "the maze should be surrounded by walls")
+                        .with_expected(["`#`"]),
+                ));
+            }
+
             match char {
                 'S' => start = Some(Position::new(column_index, line_index)),
                 'E' => finish = Some(Position::new(column_index, line_index)),
@@ -20,9 +52,8 @@ pub fn parse_input(input: &str) -> AoCInput {
         }
     }
 
-    let (Some(start), Some(finish)) = (start, finish) else {
-        panic!("AOC input doesn't seem correct. We miss S(tart) or E(nd) location!");
-    };
+    let start = start.ok_or(ParseError::MissingStart)?;
+    let finish = finish.ok_or(ParseError::MissingFinish)?;
 
-    AoCInput::new(walls, start, finish)
+    Ok(AoCInput::new(walls, start, finish))
 }
@@ -0,0 +1,23 @@
+use aoc_grid::Direction;
+use day_sixteen::{Position, best_path_tiles, dijkstra_with_turn_score, parser::parse_input};
+
+#[test]
+fn lowest_score_of_the_example() {
+    let input = parse_input(include_str!("../small_input.txt")).unwrap();
+
+    let search = dijkstra_with_turn_score(input.start.to_owned(), input.finish, &input.walls);
+
+    assert_eq!(search.goal_distance(), Some(11048));
+    assert_eq!(input.start.heading, Direction::East);
+}
+
+#[test]
+fn tiles_on_the_best_paths_of_the_example() {
+    let input = parse_input(include_str!("../small_input.txt")).unwrap();
+
+    let search = dijkstra_with_turn_score(input.start.to_owned(), input.finish, &input.walls);
+    let tiles = best_path_tiles(&search);
+
+    assert_eq!(tiles.len(), 64);
+    assert!(tiles.contains(&Position::new(1, 15)));
+}
@@ -0,0 +1,88 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use aoc_common::{random::Rng, solution::Generate};
+use day_sixteen::{DaySixteen, first_star_second_try, parser::parse_input, second_star};
+
+const CASES: u64 = 30;
+
+/// East, south, west and north, so turning clockwise adds one.
+const STEPS: [(i32, i32); 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+
+type State = (usize, usize, usize);
+
+/// The lowest score to every state (tile and heading) from the given states, taking steps
+/// backwards when `is_backwards`.
+fn scores(tiles: &[Vec<u8>], starts: &[State], is_backwards: bool) -> Vec<Vec<[u32; 4]>> {
+    let mut scores = vec![vec![[u32::MAX; 4]; tiles[0].len()]; tiles.len()];
+    let mut queue: BinaryHeap<_> = starts.iter().map(|state| Reverse((0, *state))).collect();
+
+    while let Some(Reverse((score, (x, y, heading)))) = queue.pop() {
+        if score >= scores[y][x][heading] {
+            continue;
+        }
+        scores[y][x][heading] = score;
+
+        let (dx, dy) = STEPS[heading];
+        let (dx, dy) = if is_backwards { (-dx, -dy) } else { (dx, dy) };
+        let (next_x, next_y) = ((x as i32 + dx) as usize, (y as i32 + dy) as usize);
+        if tiles[next_y][next_x] != b'#' {
+            queue.push(Reverse((score + 1, (next_x, next_y, heading))));
+        }
+        for turned in [(heading + 1) % 4, (heading + 3) % 4] {
+            queue.push(Reverse((score + 1000, (x, y, turned))));
+        }
+    }
+
+    scores
+}
+
+/// The lowest score, and the number of tiles on any of the paths with that score.
+fn reference(tiles: &[Vec<u8>]) -> (u32, usize) {
+    let find = |wanted| {
+        (0..tiles.len())
+            .find_map(|y| Some((tiles[y].iter().position(|tile| *tile == wanted)?, y)))
+            .unwrap()
+    };
+    let ((start_x, start_y), (end_x, end_y)) = (find(b'S'), find(b'E'));
+
+    let from_start = scores(tiles, &[(start_x, start_y, 0)], false);
+    let lowest = *from_start[end_y][end_x].iter().min().unwrap();
+
+    let best_ends: Vec<State> = (0..4)
+        .filter(|heading| from_start[end_y][end_x][*heading] == lowest)
+        .map(|heading| (end_x, end_y, heading))
+        .collect();
+    let to_end = scores(tiles, &best_ends, true);
+
+    let tiles_on_best_paths = (0..tiles.len())
+        .flat_map(|y| (0..tiles[0].len()).map(move |x| (x, y)))
+        .filter(|&(x, y)| {
+            (0..4).any(|heading| {
+                from_start[y][x][heading].saturating_add(to_end[y][x][heading]) == lowest
+            })
+        })
+        .count();
+
+    (lowest, tiles_on_best_paths)
+}
+
+#[test]
+fn scores_match_the_reference() {
+    for seed in 0..CASES {
+        let input = DaySixteen::generate(&mut Rng::new(seed), 5 + seed as usize);
+        let maze = parse_input(&input).unwrap();
+        let tiles: Vec<Vec<u8>> = input.lines().map(|row| row.as_bytes().to_vec()).collect();
+
+        let (lowest, tiles_on_best_paths) = reference(&tiles);
+        assert_eq!(
+            first_star_second_try(&maze),
+            Some(lowest),
+            "seed {seed}\n{input}"
+        );
+        assert_eq!(
+            second_star(&maze),
+            Some(tiles_on_best_paths),
+            "seed {seed}\n{input}"
+        );
+    }
+}
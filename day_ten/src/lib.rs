@@ -0,0 +1,134 @@
+use aoc_common::{
+    error::SyntaxError,
+    input::InputFiles,
+    random::Rng,
+    solution::{Day, Generate, Solution},
+};
+use aoc_grid::{Grid, IVec2};
+use aoc_parse::{grid_of, parse_all};
+use aoc_search::Search;
+use winnow::error::StrContextValue;
+
+pub const INPUT_FILES: InputFiles = InputFiles::new(env!("CARGO_MANIFEST_DIR"), "input_small.txt");
+
+pub const DAY: Day = Day::new::<DayTen>(10, &INPUT_FILES).with_generator::<DayTen>();
+
+pub struct DayTen;
+
+impl Solution for DayTen {
+    type Input = Map;
+    type PartOne = usize;
+    type PartTwo = usize;
+    type Error = SyntaxError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne, Self::Error> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo, Self::Error> {
+        Ok(part_two(input))
+    }
+}
+
+/// A map of `size` by `size` random heights, with trails from 0 to 9 laid over it. The trails
+/// wander randomly, so they cross, share parts and sometimes run back onto themselves.
+impl Generate for DayTen {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut heights: Vec<Vec<u8>> = (0..size)
+            .map(|_| (0..size).map(|_| rng.range(0..10) as u8).collect())
+            .collect();
+
+        for _ in 0..size {
+            let (mut x, mut y) = (rng.index(size), rng.index(size));
+            for height in 0..10 {
+                heights[y][x] = height;
+                match rng.below(4) {
+                    0 => x = (x + 1).min(size - 1),
+                    1 => x = x.saturating_sub(1),
+                    2 => y = (y + 1).min(size - 1),
+                    _ => y = y.saturating_sub(1),
+                }
+            }
+        }
+
+        heights
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|height| char::from(b'0' + height))
+                    .collect::<String>()
+                    + "\n"
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+pub struct Map {
+    coordinate_data: Grid<u8>,
+}
+
+pub fn part_one(map: &Map) -> usize {
+    // The score of a trailhead is the amount of different summits it leads to
+    trail_searches(map)
+        .map(|search| reached_summits(map, &search).count())
+        .sum()
+}
+
+pub fn part_two(map: &Map) -> usize {
+    // Every step goes one up, so every trail to a summit is as short as possible
+    trail_searches(map)
+        .map(|search| {
+            reached_summits(map, &search)
+                .map(|summit| search.count_paths_to(&summit))
+                .sum::<usize>()
+        })
+        .sum()
+}
+
+/// Searches every trail going up one step at a time, from each trailhead (height 0).
+fn trail_searches(map: &Map) -> impl Iterator<Item = Search<IVec2, usize>> + '_ {
+    map.coordinate_data
+        .iter()
+        .filter(|(_, &height)| height == 0)
+        .map(|(trailhead, _)| {
+            aoc_search::bfs(
+                [trailhead],
+                |&location| {
+                    let height = map.coordinate_data[location];
+                    map.coordinate_data
+                        .neighbours(location)
+                        .filter(move |(_, &next_height)| next_height == height + 1)
+                        .map(|(next_location, _)| next_location)
+                },
+                |_| false,
+            )
+        })
+}
+
+/// The summits (height 9) at the end of the trails that were searched.
+fn reached_summits<'a>(
+    map: &'a Map,
+    search: &'a Search<IVec2, usize>,
+) -> impl Iterator<Item = IVec2> + 'a {
+    search
+        .distances()
+        .keys()
+        .copied()
+        .filter(|location| map.coordinate_data[*location] == 9)
+}
+
+pub fn parse_input(input: &str) -> Result<Map, SyntaxError> {
+    const HEIGHT: &[StrContextValue] = &[StrContextValue::Description("a height from 0 to 9")];
+
+    Ok(Map {
+        coordinate_data: parse_all(
+            grid_of(HEIGHT, |c| c.to_digit(10).map(|height| height as u8)),
+            input,
+        )?,
+    })
+}
@@ -0,0 +1,60 @@
+use std::collections::HashSet;
+
+use aoc_common::{random::Rng, solution::Generate};
+use day_ten::{parse_input, part_one, part_two, DayTen};
+
+const CASES: u64 = 40;
+
+/// Follows every trail from the location up, adding the summit it ends at once per trail.
+fn climb(heights: &[Vec<u8>], (x, y): (usize, usize), summits: &mut Vec<(usize, usize)>) {
+    let height = heights[y][x];
+    if height == b'9' {
+        summits.push((x, y));
+        return;
+    }
+
+    let neighbours = [
+        (x.wrapping_sub(1), y),
+        (x + 1, y),
+        (x, y.wrapping_sub(1)),
+        (x, y + 1),
+    ];
+    for (next_x, next_y) in neighbours {
+        let next_height = heights.get(next_y).and_then(|row| row.get(next_x));
+        if next_height == Some(&(height + 1)) {
+            climb(heights, (next_x, next_y), summits);
+        }
+    }
+}
+
+/// The sum of the scores and of the ratings of all trailheads.
+fn reference(heights: &[Vec<u8>]) -> (usize, usize) {
+    let (mut score, mut rating) = (0, 0);
+
+    for (y, row) in heights.iter().enumerate() {
+        for x in (0..row.len()).filter(|x| row[*x] == b'0') {
+            let mut summits = vec![];
+            climb(heights, (x, y), &mut summits);
+
+            rating += summits.len();
+            score += summits.iter().collect::<HashSet<_>>().len();
+        }
+    }
+
+    (score, rating)
+}
+
+#[test]
+fn solutions_match_the_reference() {
+    for seed in 0..CASES {
+        let input = DayTen::generate(&mut Rng::new(seed), 1 + seed as usize);
+        let map = parse_input(&input).unwrap();
+        let heights: Vec<Vec<u8>> = input.lines().map(|row| row.as_bytes().to_vec()).collect();
+
+        assert_eq!(
+            (part_one(&map), part_two(&map)),
+            reference(&heights),
+            "seed {seed}\n{input}"
+        );
+    }
+}
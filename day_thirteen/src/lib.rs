@@ -0,0 +1,200 @@
+use aoc_common::{
+    error::SyntaxError,
+    input::InputFiles,
+    progress::Progress,
+    random::Rng,
+    solution::{Day, Generate, Solution},
+};
+use aoc_parse::{next_signed, parse_all, sections};
+use glam::I64Vec2;
+use rayon::iter::IntoParallelRefIterator;
+use rayon::prelude::*;
+use winnow::{Parser, Result};
+
+pub const INPUT_FILES: InputFiles = InputFiles::new(env!("CARGO_MANIFEST_DIR"), "input_small.txt");
+
+pub const DAY: Day = Day::new::<DayThirteen>(13, &INPUT_FILES).with_generator::<DayThirteen>();
+
+pub struct DayThirteen;
+
+impl Solution for DayThirteen {
+    type Input = Vec<CraneConfig>;
+    type PartOne = i64;
+    type PartTwo = i64;
+    type Error = SyntaxError;
+
+    fn parse(input: &str) -> std::result::Result<Self::Input, Self::Error> {
+        parse_crane_configs(input)
+    }
+
+    fn part_one(input: &Self::Input) -> std::result::Result<Self::PartOne, Self::Error> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> std::result::Result<Self::PartTwo, Self::Error> {
+        Ok(part_two(input))
+    }
+}
+
+/// `size` claw machines. Like in the puzzle input, the buttons move the claw in different
+/// directions, so there is at most one way to win a prize. Most prizes can be won with at most
+/// a hundred presses of each button, the others are anywhere.
+impl Generate for DayThirteen {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let machines: Vec<String> = (0..size)
+            .map(|_| {
+                let mut button = || I64Vec2::new(rng.range(10..100), rng.range(10..100));
+                let (button_a, mut button_b) = (button(), button());
+                while button_a.perp_dot(button_b) == 0 {
+                    button_b = button();
+                }
+
+                let price = match rng.chance(0.7) {
+                    true => rng.range(0..101) * button_a + rng.range(0..101) * button_b,
+                    false => I64Vec2::new(rng.range(1000..20000), rng.range(1000..20000)),
+                };
+
+                format!(
+                    "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+                    button_a.x, button_a.y, button_b.x, button_b.y, price.x, price.y
+                )
+            })
+            .collect();
+
+        machines.join("\n")
+    }
+}
+
+// Starting with a very simple but inefficient bruteforce
+pub fn part_one(crane_configs: &Vec<CraneConfig>) -> i64 {
+    let least_amount_of_tokens_required: i64 = crane_configs
+        .par_iter()
+        .flat_map(calculate_fewest_tokens_for_price_v1)
+        .sum();
+
+    least_amount_of_tokens_required
+}
+
+pub fn calculate_fewest_tokens_for_price_v1(crane_config: &CraneConfig) -> Option<i64> {
+    const BUTTON_A_TOKEN_PRICE: i64 = 3;
+    const BUTTON_B_TOKEN_PRICE: i64 = 1;
+    const MAX_TIMES_TO_PRESS_BUTTON: i64 = 100;
+
+    (0..=MAX_TIMES_TO_PRESS_BUTTON)
+        .flat_map(|a| {
+            (0..=MAX_TIMES_TO_PRESS_BUTTON).filter_map(move |b| {
+                if a * crane_config.button_a + b * crane_config.button_b == crane_config.price {
+                    Some(a * BUTTON_A_TOKEN_PRICE + b * BUTTON_B_TOKEN_PRICE)
+                } else {
+                    None
+                }
+            })
+        })
+        .min()
+}
+
+// Better.. but still brute forcing things...
+// The approach takes about 3 hours on a powerful computer. Not ideal.
+// Should have used mathematics (linear algebra) to solve this...
+pub fn part_two(crane_configs: &Vec<CraneConfig>) -> i64 {
+    const CONFIG_OFFSET: i64 = 10000000000000;
+    let progress = Progress::new("claw machines solved", Some(crane_configs.len() as u64));
+
+    let least_amount_of_tokens_required: i64 = crane_configs
+        .par_iter()
+        .map(|config| CraneConfig {
+            button_a: config.button_a,
+            button_b: config.button_b,
+            price: config.price + CONFIG_OFFSET,
+        })
+        .flat_map(|config| {
+            let tokens = calculate_fewest_tokens_for_price_v2(config);
+            progress.add(1);
+            tokens
+        })
+        .sum();
+
+    progress.finish();
+    least_amount_of_tokens_required
+}
+
+pub fn calculate_fewest_tokens_for_price_v2(crane_config: CraneConfig) -> Option<i64> {
+    const BUTTON_A_TOKEN_PRICE: i64 = 3;
+    const BUTTON_B_TOKEN_PRICE: i64 = 1;
+
+    let max_times_to_press_button_a: i64 =
+        max_presses_for_button(crane_config.button_a, crane_config.price);
+
+    // figure out the maximums for both buttons
+    let mut min_cost = None;
+
+    // We break out of loops in a way that is ok for current input data.
+    // With other data you might actually have to ... not break out of the loops
+    'outer: for a_presses in (0..=max_times_to_press_button_a).rev() {
+        let vec_after_a_presses = a_presses * crane_config.button_a;
+        let remaining = crane_config.price - vec_after_a_presses;
+
+        // Find how many times button be needs can be pressed to get to the price
+        if let Some(b_presses) = presses_to_reach(crane_config.button_b, remaining) {
+            let token_cost = a_presses * BUTTON_A_TOKEN_PRICE + b_presses * BUTTON_B_TOKEN_PRICE;
+
+            match min_cost {
+                Some(current_min_cost) if token_cost < current_min_cost => {
+                    min_cost = Some(token_cost);
+                    break 'outer;
+                }
+                None => min_cost = Some(token_cost),
+                _ => (),
+            };
+        }
+    }
+
+    min_cost
+}
+
+fn max_presses_for_button(button_vec: I64Vec2, price_vec: I64Vec2) -> i64 {
+    // Only the directions the button moves the claw in limit how often it can be pressed, and a
+    // button that doesn't move it at all is never worth pressing
+    [(button_vec.x, price_vec.x), (button_vec.y, price_vec.y)]
+        .into_iter()
+        .filter(|(button, _)| *button != 0)
+        .map(|(button, price)| price / button)
+        .min()
+        .unwrap_or(0)
+}
+
+/// The number of presses of the button moving the claw exactly to `target`, if there is one.
+fn presses_to_reach(button_vec: I64Vec2, target_vec: I64Vec2) -> Option<i64> {
+    let presses = match button_vec {
+        I64Vec2 { x: 0, y: 0 } => 0,
+        I64Vec2 { x: 0, y } => target_vec.y / y,
+        I64Vec2 { x, .. } => target_vec.x / x,
+    };
+
+    (presses >= 0 && presses * button_vec == target_vec).then_some(presses)
+}
+
+pub fn parse_crane_configs(input: &str) -> std::result::Result<Vec<CraneConfig>, SyntaxError> {
+    parse_all(sections(parse_crane), input)
+}
+
+pub fn parse_crane(input: &mut &str) -> Result<CraneConfig> {
+    Ok(CraneConfig {
+        button_a: parse_next_vector(input)?,
+        button_b: parse_next_vector(input)?,
+        price: parse_next_vector(input)?,
+    })
+}
+
+pub fn parse_next_vector(input: &mut &str) -> Result<I64Vec2> {
+    let (x, y) = (next_signed(), next_signed()).parse_next(input)?;
+
+    Ok(I64Vec2 { x, y })
+}
+
+#[derive(Debug, Clone)]
+pub struct CraneConfig {
+    pub button_a: I64Vec2,
+    pub button_b: I64Vec2,
+    pub price: I64Vec2,
+}
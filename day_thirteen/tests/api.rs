@@ -0,0 +1,52 @@
+use day_thirteen::{
+    calculate_fewest_tokens_for_price_v1, calculate_fewest_tokens_for_price_v2,
+    parse_crane_configs, parse_next_vector, CraneConfig,
+};
+use glam::I64Vec2;
+
+#[test]
+fn next_vector_skips_labels() {
+    let mut input = "Button A: X+94, Y+34\nButton B: X+22, Y+67\n";
+
+    assert_eq!(parse_next_vector(&mut input), Ok(I64Vec2::new(94, 34)));
+    assert_eq!(parse_next_vector(&mut input), Ok(I64Vec2::new(22, 67)));
+}
+
+#[test]
+fn only_some_prizes_can_be_won() {
+    let configs = parse_crane_configs(include_str!("../input_small.txt")).unwrap();
+
+    let tokens: Vec<_> = configs
+        .iter()
+        .map(calculate_fewest_tokens_for_price_v1)
+        .collect();
+
+    assert_eq!(tokens, [Some(280), None, Some(200), None]);
+}
+
+#[test]
+fn prizes_can_take_a_hundred_presses() {
+    let config = CraneConfig {
+        button_a: I64Vec2::new(2, 3),
+        button_b: I64Vec2::new(5, 1),
+        price: I64Vec2::new(200, 300),
+    };
+
+    assert_eq!(calculate_fewest_tokens_for_price_v1(&config), Some(300));
+    assert_eq!(calculate_fewest_tokens_for_price_v2(config), Some(300));
+}
+
+#[test]
+fn buttons_not_moving_the_claw_are_never_pressed() {
+    let config = |button_a, button_b, price| CraneConfig {
+        button_a,
+        button_b,
+        price,
+    };
+
+    let still = config(I64Vec2::ZERO, I64Vec2::ZERO, I64Vec2::new(5, 5));
+    assert_eq!(calculate_fewest_tokens_for_price_v2(still), None);
+
+    let sideways = config(I64Vec2::new(2, 0), I64Vec2::new(0, 3), I64Vec2::new(4, 9));
+    assert_eq!(calculate_fewest_tokens_for_price_v2(sideways), Some(9));
+}
@@ -0,0 +1,40 @@
+use aoc_common::{random::Rng, solution::Generate};
+use day_thirteen::{
+    calculate_fewest_tokens_for_price_v1, calculate_fewest_tokens_for_price_v2,
+    parse_crane_configs, CraneConfig, DayThirteen,
+};
+
+const CASES: u64 = 30;
+
+/// Solves the presses of both buttons with Cramer's rule, as the buttons are independent.
+fn reference(config: &CraneConfig, max_presses: i64) -> Option<i64> {
+    let (a, b, price) = (config.button_a, config.button_b, config.price);
+    let determinant = a.perp_dot(b);
+
+    let a_presses = price.perp_dot(b) / determinant;
+    let b_presses = a.perp_dot(price) / determinant;
+
+    let wins = a_presses * a + b_presses * b == price;
+    let allowed = (0..=max_presses).contains(&a_presses) && (0..=max_presses).contains(&b_presses);
+    (wins && allowed).then_some(a_presses * 3 + b_presses)
+}
+
+#[test]
+fn tokens_match_the_reference() {
+    for seed in 0..CASES {
+        let configs = parse_crane_configs(&DayThirteen::generate(&mut Rng::new(seed), 10)).unwrap();
+
+        for config in configs {
+            assert_eq!(
+                calculate_fewest_tokens_for_price_v1(&config),
+                reference(&config, 100),
+                "seed {seed}, {config:?}"
+            );
+            assert_eq!(
+                calculate_fewest_tokens_for_price_v2(config.clone()),
+                reference(&config, i64::MAX),
+                "seed {seed}, {config:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,220 @@
+use std::{convert::Infallible, str::FromStr};
+
+use aoc_common::{
+    input::InputFiles,
+    random::Rng,
+    solution::{Day, Generate, Solution},
+};
+use regex::Regex;
+
+pub const INPUT_FILES: InputFiles = InputFiles::new(env!("CARGO_MANIFEST_DIR"), "input_small.txt");
+
+pub const DAY: Day = Day::new::<DayThree>(3, &INPUT_FILES).with_generator::<DayThree>();
+
+pub struct DayThree;
+
+impl Solution for DayThree {
+    type Input = CorruptedMemory;
+    type PartOne = i32;
+    type PartTwo = i32;
+    // Corrupted memory can contain anything, it is never invalid
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(parse_input(input))
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne, Self::Error> {
+        Ok(exercise_1(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo, Self::Error> {
+        Ok(exercise_2(input))
+    }
+}
+
+/// Memory of `size` fragments: valid multiplier calls, toggles, calls that are almost valid and
+/// random noise. The answers are 32-bit, which overflow somewhere above a few thousand calls.
+impl Generate for DayThree {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        const NOISE: &[u8] = b"!@#$%^&*()[]{}<>,;: '+-?mulwhyselectfromwhatdon";
+
+        let mut memory = String::new();
+        for _ in 0..size {
+            let (x, y) = (rng.range(0..1000), rng.range(0..1000));
+            let fragment = match rng.below(8) {
+                0..=2 => format!("mul({x},{y})"),
+                3 => "do()".to_owned(),
+                4 => "don't()".to_owned(),
+                5 => {
+                    let digit = rng.range(1..10);
+                    rng.pick(&[
+                        format!("mul({digit}{x},{y})"),
+                        format!("mul[{x},{y}]"),
+                        format!("mul({x} ,{y})"),
+                        format!("mul({x},{y}!"),
+                        "don't".to_owned(),
+                        "do(".to_owned(),
+                    ])
+                    .clone()
+                }
+                _ => (0..rng.range(1..6))
+                    .map(|_| *rng.pick(NOISE) as char)
+                    .collect(),
+            };
+            memory.push_str(&fragment);
+        }
+
+        memory + "\n"
+    }
+}
+
+#[derive(Debug)]
+struct MultiplierCall {
+    x: i32,
+    y: i32,
+}
+
+impl MultiplierCall {
+    fn execute(&self) -> i32 {
+        self.x * self.y
+    }
+}
+
+/// The valid instructions that could be recovered from the corrupted memory.
+#[derive(Debug)]
+pub struct CorruptedMemory {
+    /// Multiplier calls together with the index at which they are located
+    multiplier_calls: Vec<(usize, MultiplierCall)>,
+    toggles: MultiplierToggles,
+}
+
+pub fn parse_input(input: &str) -> CorruptedMemory {
+    let valid_multiplier_calls = Regex::new(
+        r"(?x)
+        mul
+            \(
+                (\d{1,3}) # caputure x
+            ,
+                (\d{1,3}) # caputure y
+            \)
+        ",
+    )
+    .expect("The pattern should be valid");
+
+    let multiplier_calls = valid_multiplier_calls
+        .captures_iter(input)
+        .map(|captured| {
+            let index_of_capture = captured.get(0).expect("Group 0 is the whole match").start();
+            let (_, [x, y]) = captured.extract();
+
+            // At most three digits always fit
+            let call = MultiplierCall {
+                x: x.parse().expect("x should be a small number"),
+                y: y.parse().expect("y should be a small number"),
+            };
+
+            (index_of_capture, call)
+        })
+        .collect();
+
+    // Depending where an multiplier call is located, we want to toggle the multiplier on or off.
+    let Ok(toggles) = MultiplierToggles::from_str(input);
+
+    CorruptedMemory {
+        multiplier_calls,
+        toggles,
+    }
+}
+
+pub fn exercise_1(memory: &CorruptedMemory) -> i32 {
+    let all_multiplecations_sum: i32 = memory
+        .multiplier_calls
+        .iter()
+        .map(|(_, call)| call.execute())
+        .sum();
+
+    all_multiplecations_sum
+}
+
+pub fn exercise_2(memory: &CorruptedMemory) -> i32 {
+    let all_multiplications_sum: i32 = memory
+        .multiplier_calls
+        .iter()
+        .filter_map(|(index_of_capture, call)| {
+            let toggle_state = memory.toggles.check_state_at_index(*index_of_capture);
+
+            if matches!(toggle_state, MultiplierToggleState::On) {
+                Some(call.execute())
+            } else {
+                None
+            }
+        })
+        .sum();
+
+    all_multiplications_sum
+}
+
+#[derive(Debug)]
+struct MultiplierToggles {
+    toggles: Vec<MultiplierToggle>,
+}
+
+impl MultiplierToggles {
+    fn check_state_at_index(&self, index: usize) -> MultiplierToggleState {
+        let mut last_toggle = None;
+        for toggle in &self.toggles {
+            if toggle.index >= index {
+                break;
+            } else {
+                last_toggle = Some(toggle);
+            }
+        }
+
+        // return last toggle value. Even if not toggles have not been found yet, we start with 'On' state.
+        last_toggle
+            .map(|t| t.state.clone())
+            .unwrap_or(MultiplierToggleState::On)
+    }
+}
+
+impl FromStr for MultiplierToggles {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let switch_on_indices: Vec<_> = s
+            .match_indices("do()")
+            .map(|(index, _)| MultiplierToggle {
+                index,
+                state: MultiplierToggleState::On,
+            })
+            .collect();
+        let switch_off_toggles: Vec<_> = s
+            .match_indices("don't()")
+            .map(|(index, _)| MultiplierToggle {
+                index,
+                state: MultiplierToggleState::Off,
+            })
+            .collect();
+
+        let mut all_toggles = switch_on_indices;
+        all_toggles.extend(switch_off_toggles);
+        all_toggles.sort_by_key(|t| t.index);
+
+        Ok(MultiplierToggles {
+            toggles: all_toggles,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct MultiplierToggle {
+    index: usize,
+    state: MultiplierToggleState,
+}
+
+#[derive(Debug, Clone)]
+enum MultiplierToggleState {
+    On,
+    Off,
+}
@@ -0,0 +1,55 @@
+use aoc_common::{random::Rng, solution::Generate};
+use day_three::{exercise_1, exercise_2, parse_input, DayThree};
+
+const CASES: u64 = 50;
+
+/// A number of one to three digits at the start of the text, with the rest of the text.
+fn small_number(text: &str) -> Option<(i32, &str)> {
+    let digits = text.len() - text.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+
+    (1..=3)
+        .contains(&digits)
+        .then(|| (text[..digits].parse().unwrap(), &text[digits..]))
+}
+
+/// Scans the memory a character at a time, returning the sum of all calls and of the enabled ones.
+fn reference(memory: &str) -> (i32, i32) {
+    let (mut all, mut enabled, mut is_enabled) = (0, 0, true);
+
+    for start in 0..memory.len() {
+        let text = &memory[start..];
+        if text.starts_with("do()") {
+            is_enabled = true;
+        } else if text.starts_with("don't()") {
+            is_enabled = false;
+        } else if let Some(arguments) = text.strip_prefix("mul(") {
+            let call = small_number(arguments).and_then(|(x, rest)| {
+                let (y, rest) = small_number(rest.strip_prefix(',')?)?;
+                rest.starts_with(')').then_some(x * y)
+            });
+
+            if let Some(product) = call {
+                all += product;
+                if is_enabled {
+                    enabled += product;
+                }
+            }
+        }
+    }
+
+    (all, enabled)
+}
+
+#[test]
+fn solutions_match_the_reference() {
+    for seed in 0..CASES {
+        let input = DayThree::generate(&mut Rng::new(seed), 200);
+        let memory = parse_input(&input);
+
+        assert_eq!(
+            (exercise_1(&memory), exercise_2(&memory)),
+            reference(&input),
+            "seed {seed}"
+        );
+    }
+}
@@ -0,0 +1,363 @@
+use std::collections::HashSet;
+
+use aoc_common::{
+    error::SyntaxError,
+    image::{Image, Palette},
+    input::InputFiles,
+    random::Rng,
+    solution::{Day, Draw, Generate, Solution},
+};
+use aoc_grid::{Direction, Grid, IVec2};
+use aoc_parse::{grid, parse_all};
+use itertools::Itertools;
+use rayon::prelude::*;
+
+pub const INPUT_FILES: InputFiles = InputFiles::new(env!("CARGO_MANIFEST_DIR"), "input_small.txt");
+
+pub const DAY: Day = Day::new::<DayTwelve>(12, &INPUT_FILES)
+    .with_image::<DayTwelve>()
+    .with_generator::<DayTwelve>();
+
+pub struct DayTwelve;
+
+impl Solution for DayTwelve {
+    type Input = Garden;
+    type PartOne = usize;
+    type PartTwo = usize;
+    type Error = SyntaxError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne, Self::Error> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo, Self::Error> {
+        Ok(part_two(input))
+    }
+}
+
+impl Draw for DayTwelve {
+    /// Draws every region in its own colour, so regions of the same plant can be told apart.
+    fn draw(input: &Self::Input, _moment: Option<usize>) -> Result<Image, Self::Error> {
+        let (regions, region_count) = find_regions(input);
+        let palette = Palette::distinct(region_count);
+
+        Ok(Image::from_fn(regions.width(), regions.height(), |x, y| {
+            palette.color(regions[IVec2::new(x as i32, y as i32)])
+        }))
+    }
+}
+
+/// A garden `size` plots wide and about half as high, so it is not square. Most plots grow the
+/// same plant as the plot left of or above them, which makes regions of all kinds of shapes.
+impl Generate for DayTwelve {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let plants = ['A', 'B', 'C', 'D', 'E'];
+        let mut plots: Vec<Vec<char>> = vec![];
+
+        for y in 0..size / 2 + 1 {
+            let mut row: Vec<char> = vec![];
+            for x in 0..size {
+                let plant = match rng.below(5) {
+                    0 | 1 if x > 0 => row[x - 1],
+                    2 | 3 if y > 0 => plots[y - 1][x],
+                    _ => *rng.pick(&plants),
+                };
+                row.push(plant);
+            }
+            plots.push(row);
+        }
+
+        plots
+            .iter()
+            .map(|row| row.iter().collect::<String>() + "\n")
+            .collect()
+    }
+}
+
+/// Numbers the regions of the garden, returning the region of every plot and how many there are.
+pub fn find_regions(garden: &Garden) -> (Grid<usize>, usize) {
+    let mut regions: Grid<Option<usize>> = garden.plots.map(|_| None);
+    let mut region_count = 0;
+
+    for plot in garden.plots.positions() {
+        if regions[plot].is_some() {
+            continue;
+        }
+
+        let mut to_visit = vec![plot];
+        regions[plot] = Some(region_count);
+        while let Some(plot) = to_visit.pop() {
+            for heading in &HEADING_OPTIONS {
+                if let Some(neighbour) = garden.find_identical_neighbour(plot, heading) {
+                    if regions[neighbour].is_none() {
+                        regions[neighbour] = Some(region_count);
+                        to_visit.push(neighbour);
+                    }
+                }
+            }
+        }
+        region_count += 1;
+    }
+
+    let regions = regions.map(|region| region.expect("Every plot is in a region"));
+    (regions, region_count)
+}
+
+/// A single plot of every region, to start exploring the region from.
+fn region_starts(garden: &Garden) -> Vec<IVec2> {
+    let (regions, region_count) = find_regions(garden);
+    let mut starts = Vec::with_capacity(region_count);
+
+    // Regions are numbered in the order their first plot is found
+    for (plot, region) in regions.iter() {
+        if *region == starts.len() {
+            starts.push(plot);
+        }
+    }
+
+    starts
+}
+
+pub fn part_one(garden: &Garden) -> usize {
+    // Every region is priced on its own, so they can be priced in parallel
+    let total_costs: usize = region_starts(garden)
+        .par_iter()
+        .map(|garden_plot| {
+            let stats = calculate_region_fence_price(*garden_plot, garden, &mut HashSet::new());
+            stats.plot_count * stats.fence_count
+        })
+        .sum();
+
+    total_costs
+}
+
+fn calculate_region_fence_price(
+    plot: IVec2,
+    garden: &Garden,
+    already_checked: &mut HashSet<IVec2>,
+) -> GardenRegionStats {
+    already_checked.insert(plot);
+
+    let neighbours_of_same_kind: Vec<IVec2> = HEADING_OPTIONS
+        .iter()
+        .filter_map(|heading| garden.find_identical_neighbour(plot, heading))
+        .collect();
+
+    let region_stats = GardenRegionStats {
+        fence_count: HEADING_COUNT - neighbours_of_same_kind.len(),
+        plot_count: 1,
+    };
+
+    let undiscovered_neighbours_stats: GardenRegionStats = neighbours_of_same_kind
+        .iter()
+        .filter_map(|neightbour| {
+            if !already_checked.contains(neightbour) {
+                Some(calculate_region_fence_price(
+                    *neightbour,
+                    garden,
+                    already_checked,
+                ))
+            } else {
+                None
+            }
+        })
+        .fold(
+            GardenRegionStats {
+                plot_count: 0,
+                fence_count: 0,
+            },
+            |acc, i| i + acc,
+        );
+
+    region_stats + undiscovered_neighbours_stats
+}
+
+pub fn part_two(garden: &Garden) -> usize {
+    let total_costs: usize = region_starts(garden)
+        .par_iter()
+        .map(|garden_plot| {
+            let stats =
+                calculate_required_fences_for_region(*garden_plot, garden, &mut HashSet::new());
+
+            // Calculate discounted price for fences in all fence directions
+            let total_discounted_fences: usize = HEADING_OPTIONS
+                .iter()
+                .map(|direction| {
+                    calculate_discounted_fences_count_in_direction(&stats.fences, *direction)
+                })
+                .sum();
+
+            // We still calculate price by multiplying these values
+            stats.plot_count * total_discounted_fences
+        })
+        .sum();
+
+    total_costs
+}
+
+fn calculate_required_fences_for_region(
+    plot: IVec2,
+    garden: &Garden,
+    already_checked: &mut HashSet<IVec2>,
+) -> GardenRegionStatsV2 {
+    already_checked.insert(plot);
+
+    let (identical_neightbours, required_fences): (Vec<IVec2>, Vec<Fence>) = HEADING_OPTIONS
+        .iter()
+        .fold((vec![], vec![]), |(mut neighbours, mut fences), heading| {
+            if let Some(plot) = garden.find_identical_neighbour(plot, heading) {
+                neighbours.push(plot);
+            } else {
+                fences.push(Fence {
+                    plot_location: plot,
+                    position: *heading,
+                });
+            }
+            (neighbours, fences)
+        });
+
+    let region_stats = GardenRegionStatsV2 {
+        plot_count: 1,
+        fences: required_fences,
+    };
+
+    let undiscovered_neighbours_stats: GardenRegionStatsV2 = identical_neightbours
+        .iter()
+        .filter_map(|neightbour| {
+            if !already_checked.contains(neightbour) {
+                Some(calculate_required_fences_for_region(
+                    *neightbour,
+                    garden,
+                    already_checked,
+                ))
+            } else {
+                None
+            }
+        })
+        .fold(
+            GardenRegionStatsV2 {
+                plot_count: 0,
+                fences: vec![],
+            },
+            |acc, i| i + acc,
+        );
+
+    region_stats + undiscovered_neighbours_stats
+}
+
+fn calculate_discounted_fences_count_in_direction(
+    fences: &[Fence],
+    fence_position: Direction,
+) -> usize {
+    let mut direction_facing_fence_count = fences
+        .iter()
+        .filter(|i| i.position == fence_position)
+        .into_group_map_by(|i| match fence_position {
+            Direction::North | Direction::South => i.plot_location.y,
+            _ => i.plot_location.x,
+        });
+
+    direction_facing_fence_count
+        .values_mut()
+        .for_each(|north_fences| {
+            north_fences.sort_by_key(|f| match fence_position {
+                Direction::North | Direction::South => f.plot_location.x,
+                _ => f.plot_location.y,
+            })
+        });
+
+    // now count connected fences
+    let north_facing_count: usize = direction_facing_fence_count
+        .values()
+        .map(|fences| {
+            fences
+                .iter()
+                .tuple_windows()
+                .fold(1, |acc, (fence, next_fence)| {
+                    let diff = match fence_position {
+                        Direction::North | Direction::South => {
+                            next_fence.plot_location.x - fence.plot_location.x
+                        }
+                        _ => next_fence.plot_location.y - fence.plot_location.y,
+                    };
+
+                    if diff == 1 {
+                        acc
+                    } else {
+                        acc + 1
+                    }
+                })
+        })
+        .sum();
+
+    north_facing_count
+}
+
+pub fn parse_input(input: &str) -> Result<Garden, SyntaxError> {
+    Ok(Garden::new(parse_all(grid(Some), input)?))
+}
+
+const HEADING_COUNT: usize = 4;
+const HEADING_OPTIONS: [Direction; HEADING_COUNT] = Direction::CARDINAL;
+
+pub struct Garden {
+    plots: Grid<char>,
+}
+
+impl Garden {
+    pub fn new(plots: Grid<char>) -> Self {
+        Self { plots }
+    }
+
+    fn find_identical_neighbour(&self, plot: IVec2, heading: &Direction) -> Option<IVec2> {
+        let neighbouring_plot = plot + heading.vector();
+
+        if self.plots.get(neighbouring_plot) == Some(&self.plots[plot]) {
+            Some(neighbouring_plot)
+        } else {
+            None
+        }
+    }
+}
+
+struct GardenRegionStats {
+    plot_count: usize,
+    fence_count: usize,
+}
+
+struct GardenRegionStatsV2 {
+    plot_count: usize,
+    fences: Vec<Fence>,
+}
+
+struct Fence {
+    plot_location: IVec2,
+    /// The side of the plot the fence is on
+    position: Direction,
+}
+
+impl std::ops::Add for GardenRegionStats {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Self {
+            plot_count: self.plot_count + other.plot_count,
+            fence_count: self.fence_count + other.fence_count,
+        }
+    }
+}
+
+impl std::ops::Add for GardenRegionStatsV2 {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        let mut combined_fences = self.fences;
+        combined_fences.extend(other.fences);
+        Self {
+            plot_count: self.plot_count + other.plot_count,
+            fences: combined_fences,
+        }
+    }
+}
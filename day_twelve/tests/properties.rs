@@ -0,0 +1,69 @@
+use aoc_common::{random::Rng, solution::Generate};
+use day_twelve::{parse_input, part_one, part_two, DayTwelve};
+
+const CASES: u64 = 40;
+
+/// The plant at a location, `None` outside the garden.
+fn plant(plots: &[Vec<u8>], x: i32, y: i32) -> Option<u8> {
+    let row = plots.get(usize::try_from(y).ok()?)?;
+    row.get(usize::try_from(x).ok()?).copied()
+}
+
+/// Fills every region, counting its fences and its corners (a region has as many sides as
+/// corners). Returns the prices of both parts.
+fn reference(plots: &[Vec<u8>]) -> (usize, usize) {
+    let (width, height) = (plots[0].len() as i32, plots.len() as i32);
+    let mut seen = vec![vec![false; width as usize]; height as usize];
+    let (mut price, mut discounted_price) = (0, 0);
+
+    for (start_x, start_y) in (0..height).flat_map(|y| (0..width).map(move |x| (x, y))) {
+        if seen[start_y as usize][start_x as usize] {
+            continue;
+        }
+
+        let kind = plant(plots, start_x, start_y);
+        let (mut area, mut fences, mut corners) = (0, 0, 0);
+        let mut to_visit = vec![(start_x, start_y)];
+        seen[start_y as usize][start_x as usize] = true;
+
+        while let Some((x, y)) = to_visit.pop() {
+            area += 1;
+            let same = |dx: i32, dy: i32| plant(plots, x + dx, y + dy) == kind;
+
+            for (dx, dy) in [(1, 0), (0, 1), (-1, 0), (0, -1)] {
+                if !same(dx, dy) {
+                    fences += 1;
+                } else if !seen[(y + dy) as usize][(x + dx) as usize] {
+                    seen[(y + dy) as usize][(x + dx) as usize] = true;
+                    to_visit.push((x + dx, y + dy));
+                }
+
+                // The corner between this side and the next side clockwise
+                let (cx, cy) = (-dy, dx);
+                let is_outer_corner = !same(dx, dy) && !same(cx, cy);
+                let is_inner_corner = same(dx, dy) && same(cx, cy) && !same(dx + cx, dy + cy);
+                corners += (is_outer_corner || is_inner_corner) as usize;
+            }
+        }
+
+        price += area * fences;
+        discounted_price += area * corners;
+    }
+
+    (price, discounted_price)
+}
+
+#[test]
+fn prices_match_the_reference() {
+    for seed in 0..CASES {
+        let input = DayTwelve::generate(&mut Rng::new(seed), 1 + seed as usize);
+        let garden = parse_input(&input).unwrap();
+        let plots: Vec<Vec<u8>> = input.lines().map(|row| row.as_bytes().to_vec()).collect();
+
+        assert_eq!(
+            (part_one(&garden), part_two(&garden)),
+            reference(&plots),
+            "seed {seed}\n{input}"
+        );
+    }
+}
@@ -0,0 +1,162 @@
+use aoc_common::{
+    error::SyntaxError,
+    input::InputFiles,
+    random::Rng,
+    solution::{Day, Generate, Solution},
+};
+use aoc_parse::{lines, parse_all, signed_list};
+use winnow::ascii::space1;
+
+pub const INPUT_FILES: InputFiles = InputFiles::new(env!("CARGO_MANIFEST_DIR"), "input_small.txt");
+
+pub const DAY: Day = Day::new::<DayTwo>(2, &INPUT_FILES).with_generator::<DayTwo>();
+
+pub struct DayTwo;
+
+impl Solution for DayTwo {
+    type Input = Vec<Vec<i32>>;
+    type PartOne = usize;
+    type PartTwo = usize;
+    type Error = SyntaxError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne, Self::Error> {
+        Ok(excercise_1(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo, Self::Error> {
+        Ok(excercise_2(input))
+    }
+}
+
+/// `size` reports of 5 to 8 levels. Most steps between levels are safe, but every step has a
+/// chance to stand still, jump too far or turn around.
+impl Generate for DayTwo {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                let direction = if rng.chance(0.5) { 1 } else { -1 };
+                let mut level = rng.range(30..70);
+                let mut levels = vec![level];
+
+                for _ in 0..rng.range(4..8) {
+                    let step = if rng.chance(0.15) {
+                        *rng.pick(&[0, 4, 5, -1, -2])
+                    } else {
+                        rng.range(1..4)
+                    };
+                    level += direction * step;
+                    levels.push(level);
+                }
+
+                let levels: Vec<_> = levels.iter().map(i64::to_string).collect();
+                levels.join(" ") + "\n"
+            })
+            .collect()
+    }
+}
+
+#[derive(PartialEq)]
+enum ReportType {
+    Increasing,
+    Decreasing,
+}
+
+/// Every line of the input is a report, containing a list of levels.
+pub fn parse_input(input: &str) -> Result<Vec<Vec<i32>>, SyntaxError> {
+    parse_all(lines(signed_list(space1)), input)
+}
+
+pub fn excercise_1(reports: &[Vec<i32>]) -> usize {
+    let amount_of_safe_reports = reports
+        .iter()
+        .filter(|levels_in_line| {
+            let mut type_of_report = None;
+            let found_any_unsafe = levels_in_line.windows(2).any(|slice| {
+                let latest_delta_type = if slice[0] < slice[1] {
+                    Some(ReportType::Increasing)
+                } else {
+                    Some(ReportType::Decreasing)
+                };
+
+                // If type of report is known, it can not change anymore
+                if type_of_report.is_some() {
+                    if type_of_report != latest_delta_type {
+                        return true;
+                    }
+                } else {
+                    type_of_report = latest_delta_type;
+                }
+
+                // Also check if delta is within acceptable margins
+                let delta_value = (slice[0] - slice[1]).abs();
+                if !(1..=3).contains(&delta_value) {
+                    return true;
+                }
+
+                false
+            });
+
+            !found_any_unsafe
+        })
+        .count();
+
+    amount_of_safe_reports
+}
+
+pub fn excercise_2(reports: &[Vec<i32>]) -> usize {
+    let amount_of_safe_inputs = reports
+        .iter()
+        .filter(|numbers_in_list| {
+            let index_with_error = safety_of_report(numbers_in_list);
+
+            if let Some(index) = index_with_error {
+                // Problem can be found with reported index or the index after it, or with the
+                // index before it when that one set the wrong direction (like `1 3 2 1`)
+                (index.saturating_sub(1)..=index + 1).any(|removed_index| {
+                    let mut value_removed_list = numbers_in_list.to_vec();
+                    value_removed_list.remove(removed_index);
+
+                    safety_of_report(&value_removed_list).is_none()
+                })
+            } else {
+                true
+            }
+        })
+        .count();
+
+    amount_of_safe_inputs
+}
+
+fn safety_of_report(numbers_in_line: &[i32]) -> Option<usize> {
+    let mut type_of_report = None;
+    let found_any_unsafe = numbers_in_line.windows(2).enumerate().find(|(_, slice)| {
+        let latest_delta_kind = if slice[0] < slice[1] {
+            Some(ReportType::Increasing)
+        } else {
+            Some(ReportType::Decreasing)
+        };
+
+        // If type of report is known, it can not change anymore
+        if type_of_report.is_some() {
+            if type_of_report != latest_delta_kind {
+                return true;
+            }
+        } else {
+            type_of_report = latest_delta_kind;
+        }
+
+        // Also check if delta is within acceptable margins
+        let delta_value = (slice[0] - slice[1]).abs();
+        if !(1..=3).contains(&delta_value) {
+            return true;
+        }
+
+        false
+    });
+
+    found_any_unsafe.map(|(index, _)| index)
+}
@@ -0,0 +1,9 @@
+use day_two::{excercise_2, parse_input};
+
+#[test]
+fn dampener_can_remove_the_level_setting_the_direction() {
+    // Only removing the first level leaves a report that keeps decreasing
+    let reports = parse_input("1 3 2 1\n").unwrap();
+
+    assert_eq!(excercise_2(&reports), 1);
+}
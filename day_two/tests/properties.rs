@@ -0,0 +1,39 @@
+use aoc_common::{random::Rng, solution::Generate};
+use day_two::{excercise_1, excercise_2, parse_input, DayTwo};
+
+const CASES: u64 = 50;
+
+fn is_safe(levels: &[i32]) -> bool {
+    let steps: Vec<i32> = levels.windows(2).map(|pair| pair[1] - pair[0]).collect();
+
+    steps.iter().all(|step| (1..=3).contains(step))
+        || steps.iter().all(|step| (-3..=-1).contains(step))
+}
+
+/// Tries removing every level, one at a time.
+fn is_safe_with_dampener(levels: &[i32]) -> bool {
+    is_safe(levels)
+        || (0..levels.len()).any(|removed| {
+            let mut dampened = levels.to_vec();
+            dampened.remove(removed);
+            is_safe(&dampened)
+        })
+}
+
+#[test]
+fn solutions_match_the_reference() {
+    for seed in 0..CASES {
+        let reports = parse_input(&DayTwo::generate(&mut Rng::new(seed), 40)).unwrap();
+
+        let safe = reports.iter().filter(|levels| is_safe(levels)).count();
+        assert_eq!(excercise_1(&reports), safe, "seed {seed}");
+
+        for levels in &reports {
+            assert_eq!(
+                excercise_2(std::slice::from_ref(levels)),
+                is_safe_with_dampener(levels) as usize,
+                "seed {seed}, report {levels:?}"
+            );
+        }
+    }
+}